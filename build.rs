@@ -0,0 +1,61 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{env, fs, path::Path};
+
+/// Same as `aoc_client::ACCOUNT_ENV_VAR`, the build script can't depend on the library.
+const ACCOUNT_ENV_VAR: &str = "AOC_ACCOUNT";
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    if env::var_os("CARGO_FEATURE_EMBED_INPUTS").is_none() {
+        return;
+    }
+
+    println!("cargo:rerun-if-changed=src/inputs");
+    println!("cargo:rerun-if-env-changed={ACCOUNT_ENV_VAR}");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let mut inputs_dir = Path::new(&manifest_dir).join("src").join("inputs");
+
+    // named accounts keep their inputs in a subfolder, see `aoc_client::input_folder`.
+    let account = env::var(ACCOUNT_ENV_VAR).unwrap_or_default();
+    let account = account.trim();
+    if !account.is_empty() {
+        if !account
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            panic!(
+                "\"{account}\" is not a valid account name. Use letters, digits, \"-\" and \"_\"."
+            );
+        }
+        inputs_dir = inputs_dir.join(account);
+    }
+
+    let inputs: Vec<String> = (1..=25)
+        .map(|day| {
+            let path = inputs_dir.join(format!("{day:02}.txt"));
+            if path.is_file() {
+                format!("Some(include_str!({:?})),", path.display().to_string())
+            } else if path.with_extension("txt.enc").is_file() {
+                // the key is not available at build time, encrypted inputs have to be decrypted first.
+                panic!(
+                    "input {day:02} is only present in encrypted form. Run `cargo decrypt` before building with the embed-inputs feature."
+                );
+            } else {
+                "None,".to_string()
+            }
+        })
+        .collect();
+
+    let contents = format!(
+        "pub static INPUTS: [Option<&str>; 25] = [\n    {}\n];\n",
+        inputs.join("\n    ")
+    );
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("inputs.rs"), contents).unwrap();
+}
@@ -0,0 +1,1128 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    cmp::Ordering,
+    collections::BTreeMap,
+    env,
+    fmt::Display,
+    fs::{self, create_dir_all},
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::crypt;
+
+/// Environment variable that overrides the site the client talks to, e.g. a local mock server.
+pub const BASE_URL_ENV_VAR: &str = "AOC_BASE_URL";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// Environment variable that holds the session cookie. Takes precedence over the session file.
+pub const SESSION_ENV_VAR: &str = "ADVENT_OF_CODE_SESSION";
+/// File in the home directory that holds the session cookie, shared with aoc-cli.
+pub const SESSION_FILE: &str = ".adventofcode.session";
+/// Environment variable that selects a named account. Each account has its own session file
+/// (`~/.adventofcode.<account>.session`) and its own inputs folder (`src/inputs/<account>`).
+pub const ACCOUNT_ENV_VAR: &str = "AOC_ACCOUNT";
+
+/// Records which event year each downloaded day belongs to.
+pub const YEARS_PATH: &str = "src/puzzles/years.txt";
+
+const USER_AGENT: &str = "github.com/fspoettel/advent-of-code-rust";
+
+/// Puzzle that is used to check whether the site still serves pages this client understands.
+/// Puzzles of past events can be viewed without logging in.
+const CHECK_PUZZLE: (u8, u16) = (1, 2015);
+
+pub enum AocClientError {
+    SessionNotFound(Option<String>),
+    /// The site rejected the session cookie, it is invalid or has expired.
+    SessionExpired,
+    InvalidAccount(String),
+    /// The day does not exist in the event.
+    PuzzleNotFound(u8, u16),
+    /// The day exists but has not unlocked yet.
+    PuzzleLocked(u8, u16),
+    RateLimited,
+    /// Holds the first line of the response, which usually explains the error.
+    BadStatus(u16, String),
+    NetworkError(String),
+    UnexpectedResponse,
+    /// The site changed in a way this version of the client does not understand.
+    BadVersion(String),
+    /// A downloaded file differs from the existing one and was not replaced.
+    FileChanged(String),
+    IoError,
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound(None) => write!(
+                f,
+                "session cookie not found. Run `cargo session set`, create a \"~/{SESSION_FILE}\" file or set {SESSION_ENV_VAR}."
+            ),
+            AocClientError::SessionNotFound(Some(account)) => write!(
+                f,
+                "session cookie for account \"{account}\" not found. Run `cargo session set --account {account}`."
+            ),
+            AocClientError::SessionExpired => write!(
+                f,
+                "session cookie is invalid or has expired. Log in on the website, copy the value of the \"session\" cookie and run `cargo session set`."
+            ),
+            AocClientError::InvalidAccount(account) => write!(
+                f,
+                "\"{account}\" is not a valid account name. Use letters, digits, \"-\" and \"_\"."
+            ),
+            AocClientError::PuzzleNotFound(day, year) => {
+                write!(f, "day {day} of {year} does not exist.")
+            }
+            AocClientError::PuzzleLocked(day, year) => write!(
+                f,
+                "day {day} of {year} is not unlocked yet. Run `cargo download {day} --wait` to download it as soon as it unlocks."
+            ),
+            AocClientError::RateLimited => write!(
+                f,
+                "too many requests, the site is rate limiting this session. Wait a few minutes before trying again."
+            ),
+            AocClientError::BadStatus(status, message) if message.is_empty() => {
+                write!(f, "server responded with status {status}.")
+            }
+            AocClientError::BadStatus(status, message) => {
+                write!(f, "server responded with status {status}: {message}")
+            }
+            AocClientError::NetworkError(e) => write!(f, "request failed: {e}"),
+            AocClientError::UnexpectedResponse => {
+                write!(f, "server response could not be understood.")
+            }
+            AocClientError::BadVersion(reason) => write!(
+                f,
+                "the site is not compatible with this version of the template: {reason}. Update the template files to the latest version."
+            ),
+            AocClientError::FileChanged(path) => write!(
+                f,
+                "\"{path}\" exists with different contents. Pass --force to replace it, a backup of the existing file will be kept."
+            ),
+            AocClientError::IoError => write!(f, "could not write output files to file system."),
+        }
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(status, response) => {
+                let message = response
+                    .into_string()
+                    .ok()
+                    .and_then(|body| {
+                        body.lines()
+                            .map(str::trim)
+                            .find(|line| !line.is_empty())
+                            .map(str::to_string)
+                    })
+                    .unwrap_or_default();
+                AocClientError::BadStatus(status, message)
+            }
+            ureq::Error::Transport(e) => AocClientError::NetworkError(e.to_string()),
+        }
+    }
+}
+
+/// Result of submitting an answer, as reported by the site.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Submission {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    /// Answers were submitted too quickly. Holds the site's message, which includes the wait time.
+    TooRecent(String),
+    /// The part was already solved or is not unlocked yet.
+    WrongLevel,
+    Unknown(String),
+}
+
+impl Submission {
+    fn parse(message: &str) -> Self {
+        if message.contains("That's the right answer") {
+            Submission::Correct
+        } else if message.contains("That's not the right answer") {
+            if message.contains("too high") {
+                Submission::TooHigh
+            } else if message.contains("too low") {
+                Submission::TooLow
+            } else {
+                Submission::Incorrect
+            }
+        } else if message.contains("You gave an answer too recently") {
+            Submission::TooRecent(message.to_string())
+        } else if message.contains("You don't seem to be solving the right level") {
+            Submission::WrongLevel
+        } else {
+            Submission::Unknown(message.to_string())
+        }
+    }
+}
+
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(session: &str) -> Self {
+        let base_url = env::var(BASE_URL_ENV_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+
+        Self {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+        }
+    }
+
+    /// Creates a client with the session cookie of the active account.
+    pub fn from_env() -> Result<Self, AocClientError> {
+        Ok(Self::new(&read_session(account()?.as_deref())?))
+    }
+
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    fn get(&self, path: &str) -> Result<String, AocClientError> {
+        self.agent
+            .get(&format!("{}{path}", self.base_url))
+            .set("Cookie", &format!("session={}", self.session))
+            .call()?
+            .into_string()
+            .map_err(|_| AocClientError::UnexpectedResponse)
+    }
+
+    pub fn get_input(&self, day: u8, year: u16) -> Result<String, AocClientError> {
+        self.get(&format!("/{year}/day/{day}/input"))
+            .map_err(|e| classify(e, day, year))
+    }
+
+    /// Fetches the JSON of a private leaderboard. Without a valid session the site redirects
+    /// to a html page instead.
+    pub fn get_leaderboard(&self, id: u64, year: u16) -> Result<String, AocClientError> {
+        let json = self.get(&format!("/{year}/leaderboard/private/view/{id}.json"))?;
+
+        if !json.trim_start().starts_with('{') {
+            return Err(AocClientError::SessionExpired);
+        }
+
+        Ok(json)
+    }
+
+    /// Checks that the site accepts the session and returns the name of the logged in user.
+    pub fn check_session(&self) -> Result<String, AocClientError> {
+        let html = self.get("/")?;
+        logged_in_user(&html).ok_or(AocClientError::SessionExpired)
+    }
+
+    /// Checks that puzzle pages still have the markup this client parses.
+    pub fn check(&self) -> Result<(), AocClientError> {
+        let (day, year) = CHECK_PUZZLE;
+        let puzzle = self.get_puzzle(day, year)?;
+
+        if !puzzle.starts_with("## --- Day ") {
+            return Err(AocClientError::BadVersion(
+                "puzzle descriptions have no title".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Fetches the puzzle page and converts its description to markdown.
+    pub fn get_puzzle(&self, day: u8, year: u16) -> Result<String, AocClientError> {
+        let html = self
+            .get(&format!("/{year}/day/{day}"))
+            .map_err(|e| classify(e, day, year))?;
+        let articles = articles(&html);
+
+        if articles.is_empty() {
+            return Err(AocClientError::BadVersion(
+                "puzzle pages have no <article> element".to_string(),
+            ));
+        }
+
+        let markdown: Vec<_> = articles.into_iter().map(html_to_markdown).collect();
+        Ok(markdown.join("\n"))
+    }
+
+    pub fn submit(
+        &self,
+        day: u8,
+        year: u16,
+        part: u8,
+        answer: &str,
+    ) -> Result<Submission, AocClientError> {
+        let html = self
+            .agent
+            .post(&format!("{}/{year}/day/{day}/answer", self.base_url))
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|e| classify(e.into(), day, year))?
+            .into_string()
+            .map_err(|_| AocClientError::UnexpectedResponse)?;
+
+        let article = articles(&html)
+            .into_iter()
+            .next()
+            .ok_or(AocClientError::UnexpectedResponse)?;
+
+        Ok(Submission::parse(&html_to_markdown(article)))
+    }
+}
+
+/// Turns a failed request for a day into a more specific error. The site answers with 400
+/// when the session is missing or expired, with 404 for days that are locked or don't exist
+/// and with 429 when it rate limits the session.
+fn classify(e: AocClientError, day: u8, year: u16) -> AocClientError {
+    match e {
+        AocClientError::BadStatus(400, _) => AocClientError::SessionExpired,
+        AocClientError::BadStatus(404, _) if now_secs() < unlock_time(day, year) => {
+            AocClientError::PuzzleLocked(day, year)
+        }
+        AocClientError::BadStatus(404, _) => AocClientError::PuzzleNotFound(day, year),
+        AocClientError::BadStatus(429, _) => AocClientError::RateLimited,
+        e => e,
+    }
+}
+
+/// Returns the name of the user shown in the page header, if the page was served to a logged in user.
+fn logged_in_user(html: &str) -> Option<String> {
+    let start = html.find("<div class=\"user\">")? + "<div class=\"user\">".len();
+    let name = html[start..].split('<').next()?.trim();
+    Some(decode_entities(name))
+}
+
+fn is_valid_account(account: &str) -> bool {
+    !account.is_empty()
+        && account
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// Returns the account selected with the account environment variable, `None` for the default account.
+pub fn account() -> Result<Option<String>, AocClientError> {
+    match env::var(ACCOUNT_ENV_VAR) {
+        Ok(account) if !account.trim().is_empty() => {
+            let account = account.trim();
+            if !is_valid_account(account) {
+                return Err(AocClientError::InvalidAccount(account.to_string()));
+            }
+            Ok(Some(account.to_string()))
+        }
+        _ => Ok(None),
+    }
+}
+
+fn home_dir() -> Option<PathBuf> {
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(PathBuf::from)
+}
+
+pub fn session_file_name(account: Option<&str>) -> String {
+    match account {
+        Some(account) => format!(".adventofcode.{account}.session"),
+        None => SESSION_FILE.to_string(),
+    }
+}
+
+/// Reads the session cookie of an account. The session environment variable only applies
+/// to the default account.
+pub fn read_session(account: Option<&str>) -> Result<String, AocClientError> {
+    let not_found = || AocClientError::SessionNotFound(account.map(str::to_string));
+
+    if let Some(account) = account.filter(|account| !is_valid_account(account)) {
+        return Err(AocClientError::InvalidAccount(account.to_string()));
+    }
+
+    if account.is_none() {
+        if let Ok(session) = env::var(SESSION_ENV_VAR) {
+            if !session.trim().is_empty() {
+                return Ok(session.trim().to_string());
+            }
+        }
+    }
+
+    let path = home_dir()
+        .ok_or_else(not_found)?
+        .join(session_file_name(account));
+    let session = fs::read_to_string(path).map_err(|_| not_found())?;
+
+    if session.trim().is_empty() {
+        return Err(not_found());
+    }
+
+    Ok(session.trim().to_string())
+}
+
+/// Stores the session cookie of an account in its session file. Returns the path of the file.
+pub fn write_session(account: Option<&str>, session: &str) -> Result<PathBuf, AocClientError> {
+    if let Some(account) = account.filter(|account| !is_valid_account(account)) {
+        return Err(AocClientError::InvalidAccount(account.to_string()));
+    }
+
+    let path = home_dir()
+        .ok_or(AocClientError::IoError)?
+        .join(session_file_name(account));
+    fs::write(&path, format!("{}\n", session.trim())).map_err(|_| AocClientError::IoError)?;
+    Ok(path)
+}
+
+/// Lists the named accounts that have a session file.
+pub fn list_accounts() -> Vec<String> {
+    let Some(entries) = home_dir().and_then(|home| fs::read_dir(home).ok()) else {
+        return Vec::new();
+    };
+
+    let mut accounts: Vec<_> = entries
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            let account = name
+                .strip_prefix(".adventofcode.")?
+                .strip_suffix(".session")?;
+            is_valid_account(account).then(|| account.to_string())
+        })
+        .collect();
+
+    accounts.sort();
+    accounts
+}
+
+/// Puzzles unlock at midnight US Eastern time, which is UTC-5 in December.
+pub const UNLOCK_UTC_OFFSET_SECS: i64 = -5 * 3600;
+
+pub fn now_secs() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs() as i64)
+}
+
+/// The current (year, month, day) in the time zone puzzles are released in.
+fn server_date() -> (i64, u32, u32) {
+    civil_from_days((now_secs() + UNLOCK_UTC_OFFSET_SECS).div_euclid(86_400))
+}
+
+/// The year of the most recent event: the current year from December onwards, the previous one before.
+pub fn current_event_year() -> u16 {
+    let (year, month, _) = server_date();
+
+    if month == 12 {
+        year as u16
+    } else {
+        (year - 1) as u16
+    }
+}
+
+/// The current year in the time zone puzzles are released in.
+pub fn current_year() -> u16 {
+    server_date().0 as u16
+}
+
+/// Unix timestamp at which the puzzle of a day unlocks: midnight US Eastern time.
+pub fn unlock_time(day: u8, year: u16) -> i64 {
+    days_from_civil(i64::from(year), 12, u32::from(day)) * 86_400 - UNLOCK_UTC_OFFSET_SECS
+}
+
+/// Number of puzzles in an event. Events have 12 puzzles since 2025.
+pub fn event_days(year: u16) -> u8 {
+    if year >= 2025 {
+        12
+    } else {
+        25
+    }
+}
+
+/// Number of puzzles of `year`'s event that are unlocked by now.
+pub fn released_days(year: u16) -> u8 {
+    let (current_year, month, day) = server_date();
+
+    match i64::from(year).cmp(&current_year) {
+        Ordering::Less => event_days(year),
+        Ordering::Equal if month == 12 => event_days(year).min(day as u8),
+        _ => 0,
+    }
+}
+
+/// Converts days since the unix epoch to a (year, month, day) date.
+/// see: http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+pub fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Converts a (year, month, day) date to days since the unix epoch.
+/// see: http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+pub fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let month = i64::from(month);
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Folder that holds the inputs of an account. Named accounts keep their inputs in a subfolder.
+pub fn input_folder(account: Option<&str>) -> String {
+    match account {
+        Some(account) => format!("src/inputs/{account}"),
+        None => "src/inputs".to_string(),
+    }
+}
+
+/// Path of the input of a day for the active account.
+pub fn get_input_path(day: u8) -> String {
+    let account = account().ok().flatten();
+    let day_padded = format!("{day:02}");
+    format!("{}/{day_padded}.txt", input_folder(account.as_deref()))
+}
+
+pub fn get_puzzle_path(day: u8) -> String {
+    let day_padded = format!("{day:02}");
+    format!("src/puzzles/{day_padded}.md")
+}
+
+/// Outcome of writing a downloaded file.
+#[derive(Debug, PartialEq, Eq)]
+pub enum FileStatus {
+    Created,
+    Unchanged,
+    /// The file was replaced. Holds the path of the backup of the previous contents, if one was made.
+    Updated(Option<String>),
+}
+
+/// Formats a unix timestamp as `YYYYMMDD-HHMMSS` (UTC).
+pub fn format_timestamp(secs: i64) -> String {
+    let (year, month, day) = civil_from_days(secs.div_euclid(86_400));
+    let secs = secs.rem_euclid(86_400);
+    format!(
+        "{year:04}{month:02}{day:02}-{:02}{:02}{:02}",
+        secs / 3600,
+        secs % 3600 / 60,
+        secs % 60
+    )
+}
+
+/// Writes `contents` to `path` without losing data: existing files with different contents
+/// are only replaced if `force` is set, and are backed up first if `backup` is set.
+pub fn safe_write(
+    path: &str,
+    contents: &str,
+    force: bool,
+    backup: bool,
+) -> Result<FileStatus, AocClientError> {
+    let status = match fs::read_to_string(path) {
+        Ok(existing) if existing == contents => return Ok(FileStatus::Unchanged),
+        Ok(existing) if existing.is_empty() => FileStatus::Created,
+        Ok(_) if !force => return Err(AocClientError::FileChanged(path.to_string())),
+        Ok(existing) if backup => {
+            let backup_path = format!("{path}.{}.bak", format_timestamp(now_secs()));
+            fs::write(&backup_path, existing).map_err(|_| AocClientError::IoError)?;
+            FileStatus::Updated(Some(backup_path))
+        }
+        Ok(_) => FileStatus::Updated(None),
+        Err(_) => FileStatus::Created,
+    };
+
+    fs::write(path, contents).map_err(|_| AocClientError::IoError)?;
+    Ok(status)
+}
+
+fn report(kind: &str, path: &str, status: &FileStatus) {
+    match status {
+        FileStatus::Created => println!("🎄 Successfully wrote {kind} to \"{path}\"."),
+        FileStatus::Unchanged => println!("🎄 {kind} \"{path}\" is unchanged."),
+        FileStatus::Updated(Some(backup_path)) => {
+            println!("🎄 Updated {kind} \"{path}\", previous version saved to \"{backup_path}\".")
+        }
+        FileStatus::Updated(None) => println!("🎄 Updated {kind} \"{path}\"."),
+    }
+}
+
+/// Downloads input and puzzle description of a day. An existing input that differs from the
+/// downloaded one is only replaced if `force` is set. Puzzle descriptions are always updated,
+/// as they grow once part two is unlocked.
+pub fn download(day: u8, year: Option<u16>, force: bool) -> Result<(), AocClientError> {
+    let client = Client::from_env()?;
+    let year = year.unwrap_or_else(current_event_year);
+
+    let input_path = get_input_path(day);
+    let puzzle_path = get_puzzle_path(day);
+    create_dir_all(input_folder(account()?.as_deref())).map_err(|_| AocClientError::IoError)?;
+    create_dir_all("src/puzzles").map_err(|_| AocClientError::IoError)?;
+
+    println!("Downloading input for day {day}, {year}...");
+    let input = client.get_input(day, year)?;
+    let input_status = safe_write(&input_path, &input, force, true)?;
+
+    println!("Fetching puzzle for day {day}, {year}...");
+    let puzzle = client.get_puzzle(day, year)?;
+    let puzzle_status = safe_write(&puzzle_path, &puzzle, true, false)?;
+    record_year(day, year)?;
+
+    println!("---");
+    report("input", &input_path, &input_status);
+    report("puzzle", &puzzle_path, &puzzle_status);
+    Ok(())
+}
+
+fn read_years() -> BTreeMap<u8, u16> {
+    parse_years(&fs::read_to_string(YEARS_PATH).unwrap_or_default())
+}
+
+/// Parses the `NN: YYYY` lines of the years file.
+fn parse_years(contents: &str) -> BTreeMap<u8, u16> {
+    contents
+        .lines()
+        .filter_map(|line| {
+            let (day, year) = line.split_once(':')?;
+            Some((day.trim().parse().ok()?, year.trim().parse().ok()?))
+        })
+        .collect()
+}
+
+/// The event year the local files of `day` were downloaded for, if known.
+pub fn downloaded_year(day: u8) -> Option<u16> {
+    read_years().get(&day).copied()
+}
+
+fn record_year(day: u8, year: u16) -> Result<(), AocClientError> {
+    let mut years = read_years();
+    years.insert(day, year);
+
+    let contents: String = years
+        .iter()
+        .map(|(day, year)| format!("{day:02}: {year}\n"))
+        .collect();
+    fs::write(YEARS_PATH, contents).map_err(|_| AocClientError::IoError)
+}
+
+/// A day counts as downloaded once its input file has content or an encrypted copy exists,
+/// and it was not downloaded for a different year.
+pub fn is_downloaded(day: u8, year: u16) -> bool {
+    let input_path = get_input_path(day);
+    let present = fs::metadata(&input_path).is_ok_and(|metadata| metadata.len() > 0)
+        || crypt::encrypted_path(Path::new(&input_path)).exists();
+
+    present && downloaded_year(day).is_none_or(|downloaded| downloaded == year)
+}
+
+/// Returns the puzzle description. The local copy in `src/puzzles` is preferred unless `refresh` is set
+/// or it belongs to a different year than the one requested, otherwise the puzzle is fetched.
+/// Fetched puzzles are cached unless that would replace the copy of another year.
+pub fn read(day: u8, year: Option<u16>, refresh: bool) -> Result<String, AocClientError> {
+    let puzzle_path = get_puzzle_path(day);
+    let local_year = downloaded_year(day);
+    let local = fs::read_to_string(&puzzle_path)
+        .ok()
+        .or_else(|| crypt::read_encrypted(Path::new(&puzzle_path)).ok());
+
+    let matches_year = year.is_none() || local_year == year;
+
+    if let Some(puzzle) = local.as_ref().filter(|_| !refresh && matches_year) {
+        return Ok(puzzle.clone());
+    }
+
+    let client = Client::from_env()?;
+    let year = year.or(local_year).unwrap_or_else(current_event_year);
+
+    let puzzle = client.get_puzzle(day, year)?;
+
+    if local.is_none() || local_year == Some(year) {
+        create_dir_all("src/puzzles").map_err(|_| AocClientError::IoError)?;
+        fs::write(&puzzle_path, &puzzle).map_err(|_| AocClientError::IoError)?;
+        record_year(day, year)?;
+    }
+
+    Ok(puzzle)
+}
+
+/// Returns the inner html of every `<article>` element of a page.
+fn articles(html: &str) -> Vec<&str> {
+    let mut articles = Vec::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(content_start) = rest[start..].find('>').map(|idx| start + idx + 1) else {
+            break;
+        };
+        let Some(end) = rest[content_start..].find("</article>") else {
+            break;
+        };
+        articles.push(&rest[content_start..content_start + end]);
+        rest = &rest[content_start + end..];
+    }
+
+    articles
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest.find(';').map(|end| (&rest[1..end], end));
+        let ch = entity.and_then(|(name, _)| match name {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => name
+                .strip_prefix("#x")
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| name.strip_prefix('#').map(|dec| dec.parse()))
+                .and_then(|code| code.ok())
+                .and_then(char::from_u32),
+        });
+
+        match (ch, entity) {
+            (Some(ch), Some((_, end))) => {
+                decoded.push(ch);
+                rest = &rest[end + 1..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+/// Converts the subset of html used in puzzle descriptions to markdown.
+pub fn html_to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut rest = html;
+
+    let mut in_pre = false;
+    // inline code is buffered so emphasis around its full content can be moved outside.
+    let mut code: Option<(String, bool)> = None;
+    let mut links: Vec<String> = Vec::new();
+
+    fn end_block(out: &mut String) {
+        let trimmed_len = out.trim_end().len();
+        out.truncate(trimmed_len);
+        if !out.is_empty() {
+            out.push_str("\n\n");
+        }
+    }
+
+    while !rest.is_empty() {
+        let (text, tag) = match rest.find('<') {
+            Some(0) => {
+                let end = rest.find('>').map_or(rest.len(), |idx| idx + 1);
+                let tag = &rest[..end];
+                rest = &rest[end..];
+                (None, Some(tag))
+            }
+            Some(idx) => {
+                let text = &rest[..idx];
+                rest = &rest[idx..];
+                (Some(text), None)
+            }
+            None => {
+                let text = rest;
+                rest = "";
+                (Some(text), None)
+            }
+        };
+
+        if let Some(text) = text {
+            let text = decode_entities(text);
+            if let Some((buffer, _)) = code.as_mut() {
+                buffer.push_str(&text);
+            } else if in_pre {
+                out.push_str(&text);
+            } else {
+                for ch in text.chars() {
+                    if !ch.is_whitespace() {
+                        out.push(ch);
+                    } else if !(out.is_empty() || out.ends_with([' ', '\n'])) {
+                        out.push(' ');
+                    }
+                }
+            }
+            continue;
+        }
+
+        let Some(tag) = tag else { continue };
+        let inner = tag.trim_start_matches('<').trim_end_matches('>');
+        let closing = inner.starts_with('/');
+        let inner = inner.trim_start_matches('/').trim_end_matches('/');
+        let name = inner
+            .split_whitespace()
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        match (name.as_str(), closing) {
+            ("h1" | "h2" | "h3", false) => {
+                end_block(&mut out);
+                out.push_str("## ");
+            }
+            ("h1" | "h2" | "h3" | "p", true) => end_block(&mut out),
+            ("p" | "ul", false) => end_block(&mut out),
+            ("ul", true) => end_block(&mut out),
+            ("li", false) => {
+                if !(out.is_empty() || out.ends_with("\n\n")) {
+                    let trimmed_len = out.trim_end().len();
+                    out.truncate(trimmed_len);
+                    out.push('\n');
+                }
+                out.push_str("- ");
+            }
+            ("pre", false) => {
+                end_block(&mut out);
+                out.push_str("```\n");
+                in_pre = true;
+            }
+            ("pre", true) => {
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("```");
+                end_block(&mut out);
+                in_pre = false;
+            }
+            ("code", false) if !in_pre => code = Some((String::new(), false)),
+            ("code", true) if !in_pre => {
+                if let Some((buffer, emphasized)) = code.take() {
+                    let buffer = buffer.replace('\n', " ");
+                    if emphasized {
+                        out.push_str(&format!("*`{buffer}`*"));
+                    } else {
+                        out.push_str(&format!("`{buffer}`"));
+                    }
+                }
+            }
+            ("em", false) => match code.as_mut() {
+                Some((buffer, emphasized)) if buffer.is_empty() => *emphasized = true,
+                Some(_) => {}
+                None if in_pre => {}
+                None => out.push('*'),
+            },
+            ("em", true) if code.is_none() && !in_pre => out.push('*'),
+            ("a", false) => {
+                let href = inner
+                    .split("href=\"")
+                    .nth(1)
+                    .and_then(|href| href.split('"').next())
+                    .map(decode_entities)
+                    .unwrap_or_default();
+                links.push(href);
+                out.push('[');
+            }
+            ("a", true) => {
+                let href = links.pop().unwrap_or_default();
+                out.push_str(&format!("]({href})"));
+            }
+            ("br", _) => out.push('\n'),
+            _ => {}
+        }
+    }
+
+    let trimmed_len = out.trim_end().len();
+    out.truncate(trimmed_len);
+    out.push('\n');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+    };
+
+    const PUZZLE_HTML: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Calorie Counting ---</h2><p>The Elves take turns
+writing down the number of <em>Calories</em> &amp; more, see <a href="/2022/about">about</a>.</p>
+<pre><code>1000
+<em>2000</em>
+</code></pre>
+<ul><li>The first Elf: <code>6000</code></li><li>The second Elf: <code><em>24000</em></code></li></ul>
+</article>
+<p>Your puzzle answer was <code>1</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Find the top three.</p></article>
+</main></body></html>"#;
+
+    #[test]
+    fn test_html_to_markdown() {
+        let markdown: Vec<_> = articles(PUZZLE_HTML)
+            .into_iter()
+            .map(html_to_markdown)
+            .collect();
+
+        assert_eq!(
+            markdown.join("\n"),
+            "## --- Day 1: Calorie Counting ---\n\n\
+            The Elves take turns writing down the number of *Calories* & more, see [about](/2022/about).\n\n\
+            ```\n1000\n2000\n```\n\n\
+            - The first Elf: `6000`\n\
+            - The second Elf: *`24000`*\n\
+            \n## --- Part Two ---\n\nFind the top three.\n"
+        );
+    }
+
+    #[test]
+    fn test_decode_entities() {
+        assert_eq!(
+            decode_entities("a &lt;b&gt; &#39;c&#x27; &foo d&"),
+            "a <b> 'c' &foo d&"
+        );
+    }
+
+    #[test]
+    fn test_parse_years() {
+        assert_eq!(
+            parse_years("01: 2022\n12: 2020\ninvalid\n"),
+            BTreeMap::from([(1, 2022), (12, 2020)])
+        );
+    }
+
+    #[test]
+    fn test_days_from_civil() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2022, 12, 1), 19_327);
+        assert_eq!(days_from_civil(2024, 3, 1), 19_783);
+        assert_eq!(civil_from_days(days_from_civil(2024, 2, 29)), (2024, 2, 29));
+    }
+
+    #[test]
+    fn test_unlock_time() {
+        // 2022-12-01 05:00:00 UTC
+        assert_eq!(unlock_time(1, 2022), 1_669_870_800);
+        assert_eq!(unlock_time(25, 2022), 1_669_870_800 + 24 * 86_400);
+    }
+
+    #[test]
+    fn test_format_timestamp() {
+        assert_eq!(format_timestamp(0), "19700101-000000");
+        assert_eq!(format_timestamp(1_669_870_800), "20221201-050000");
+    }
+
+    #[test]
+    fn test_safe_write() {
+        let dir = env::temp_dir().join(format!("aoc_safe_write_{}", std::process::id()));
+        create_dir_all(&dir).unwrap();
+        let path = dir.join("01.txt").to_str().unwrap().to_string();
+
+        assert_eq!(
+            safe_write(&path, "1\n", false, true).ok(),
+            Some(FileStatus::Created)
+        );
+        assert_eq!(
+            safe_write(&path, "1\n", false, true).ok(),
+            Some(FileStatus::Unchanged)
+        );
+        assert!(matches!(
+            safe_write(&path, "2\n", false, true),
+            Err(AocClientError::FileChanged(_))
+        ));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1\n");
+
+        let Ok(FileStatus::Updated(Some(backup_path))) = safe_write(&path, "2\n", true, true)
+        else {
+            panic!("expected file to be updated with a backup");
+        };
+        assert_eq!(fs::read_to_string(&path).unwrap(), "2\n");
+        assert_eq!(fs::read_to_string(backup_path).unwrap(), "1\n");
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_civil_from_days() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(19_327), (2022, 12, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+    }
+
+    /// Serves `responses` in order and reports each request's head and body.
+    fn mock_server(responses: Vec<(u16, &'static str)>) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(len) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                        content_length = len.trim().parse().unwrap();
+                    }
+                    if line == "\r\n" {
+                        break;
+                    }
+                    request.push_str(&line);
+                }
+                let mut body_buf = vec![0; content_length];
+                reader.read_exact(&mut body_buf).unwrap();
+                request.push_str(&String::from_utf8(body_buf).unwrap());
+                tx.send(request).unwrap();
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+        });
+
+        (base_url, rx)
+    }
+
+    #[test]
+    fn test_client() {
+        let (base_url, requests) = mock_server(vec![
+            (200, "1000\n2000\n"),
+            (200, PUZZLE_HTML),
+            (
+                200,
+                "<main><article><p>That's not the right answer; your answer is too high.</p></article></main>",
+            ),
+            (404, "Not Found"),
+            (404, "Please don't repeatedly request this endpoint before it unlocks!"),
+            (429, "Too Many Requests"),
+            (500, "\n  Internal Server Error  \nTry again later."),
+            (400, "Puzzle inputs differ by user.  Please log in to get your puzzle input."),
+            (200, "<header><div class=\"user\">Alice &amp; Bob <span class=\"star-count\">16*</span></div></header>"),
+            (200, "<header><a href=\"/2022/auth/login\">[Log In]</a></header>"),
+            (200, PUZZLE_HTML),
+            (200, "<main><p>Puzzles moved.</p></main>"),
+        ]);
+        let client = Client::new("abc\n").with_base_url(&base_url);
+
+        assert_eq!(
+            client.get_input(1, 2022).ok().as_deref(),
+            Some("1000\n2000\n")
+        );
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("GET /2022/day/1/input HTTP/1.1"));
+        assert!(request.contains("session=abc\r\n"));
+
+        let puzzle = client.get_puzzle(1, 2022).ok().unwrap();
+        assert!(puzzle.starts_with("## --- Day 1: Calorie Counting ---"));
+        assert!(requests
+            .recv()
+            .unwrap()
+            .starts_with("GET /2022/day/1 HTTP/1.1"));
+
+        assert_eq!(
+            client.submit(1, 2022, 2, "45000").ok(),
+            Some(Submission::TooHigh)
+        );
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("POST /2022/day/1/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=45000"));
+
+        assert!(matches!(
+            client.get_input(26, 2022),
+            Err(AocClientError::PuzzleNotFound(26, 2022))
+        ));
+        assert!(matches!(
+            client.get_puzzle(1, 2100),
+            Err(AocClientError::PuzzleLocked(1, 2100))
+        ));
+        assert!(matches!(
+            client.get_input(1, 2022),
+            Err(AocClientError::RateLimited)
+        ));
+        assert_eq!(
+            client.get_input(1, 2022).err().map(|e| e.to_string()),
+            Some("server responded with status 500: Internal Server Error".to_string())
+        );
+
+        assert!(matches!(
+            client.get_input(1, 2022),
+            Err(AocClientError::SessionExpired)
+        ));
+
+        assert_eq!(client.check_session().ok().as_deref(), Some("Alice & Bob"));
+        assert!(matches!(
+            client.check_session(),
+            Err(AocClientError::SessionExpired)
+        ));
+
+        assert!(client.check().is_ok());
+        assert!(matches!(client.check(), Err(AocClientError::BadVersion(_))));
+    }
+
+    #[test]
+    fn test_accounts() {
+        assert_eq!(session_file_name(None), ".adventofcode.session");
+        assert_eq!(
+            session_file_name(Some("alice")),
+            ".adventofcode.alice.session"
+        );
+        assert_eq!(input_folder(None), "src/inputs");
+        assert_eq!(input_folder(Some("alice")), "src/inputs/alice");
+
+        assert!(is_valid_account("team-a_2"));
+        assert!(!is_valid_account("../alice"));
+        assert!(matches!(
+            read_session(Some("../alice")),
+            Err(AocClientError::InvalidAccount(_))
+        ));
+    }
+
+    #[test]
+    fn test_submission_parse() {
+        assert_eq!(
+            Submission::parse("That's the right answer! You are one gold star closer."),
+            Submission::Correct
+        );
+        assert_eq!(
+            Submission::parse("That's not the right answer. If you're stuck, ..."),
+            Submission::Incorrect
+        );
+        assert_eq!(
+            Submission::parse(
+                "You don't seem to be solving the right level. Did you already complete it?"
+            ),
+            Submission::WrongLevel
+        );
+        assert!(matches!(
+            Submission::parse("You gave an answer too recently; You have 35s left to wait."),
+            Submission::TooRecent(_)
+        ));
+    }
+}
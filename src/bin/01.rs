@@ -1,3 +1,5 @@
+use advent_of_code::helpers::paragraphs;
+
 fn get_top_cals(input: &str, top_no: usize) -> Option<u32> {
     let sum_each_inventory = |inv: &str| {
         inv
@@ -9,8 +11,7 @@ fn get_top_cals(input: &str, top_no: usize) -> Option<u32> {
             .sum()
     };
 
-    let mut inventories: Vec<u32> = input
-        .split("\n\n")
+    let mut inventories: Vec<u32> = paragraphs(input)
         .map(sum_each_inventory)
         .collect();
 
@@ -39,6 +40,8 @@ fn main() {
 mod tests {
     use super::*;
 
+    advent_of_code::test_examples!(1, part_one, part_two);
+
     #[test]
     fn test_part_one() {
         let input = advent_of_code::read_file("examples", 1);
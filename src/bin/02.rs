@@ -1,3 +1,4 @@
+#![allow(clippy::upper_case_acronyms)]
 
 #[derive(PartialEq, Copy, Clone)]
 enum Shape {
@@ -180,6 +181,8 @@ fn main() {
 mod tests {
     use super::*;
 
+    advent_of_code::test_examples!(2, part_one, part_two);
+
     #[test]
     fn test_part_one() {
         let input = advent_of_code::read_file("examples", 2);
@@ -1,40 +1,23 @@
-use std::collections::HashSet;
+use advent_of_code::helpers::{priority, LetterSet};
 use itertools::Itertools;
 
-const ALPHABET: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
-
-fn rucksack_items_to_set(items: &str) -> HashSet<char> {
-    HashSet::from_iter(items.chars())
-}
-
-fn common_item(group: impl Iterator<Item = HashSet<char>> ) -> char {
-    let common_items: HashSet<char> = group.reduce(
-        |set1, set2| set1.intersection(&set2).copied().collect()
+fn common_item(group: impl Iterator<Item = LetterSet> ) -> char {
+    let common_items: LetterSet = group.reduce(
+        |set1, set2| set1 & set2
     ).unwrap();
 
     assert_eq!(common_items.len(), 1);
 
-    let common_item = *common_items.iter().collect::<Vec<&char>>()[0];
-
-    common_item
+    common_items.iter().next().unwrap()
 }
 
 fn sum_priorities(priorities: impl Iterator<Item = char>) -> u32 {
-    let alphabet_vec: Vec<char> = ALPHABET.chars().collect();
-    let sum_priorities: usize = priorities
+    priorities
         .map(
             |item| 
-            alphabet_vec
-                .iter()
-                .position(
-                    |&char| char == item
-                )
-                .unwrap() 
-                + 1
+            priority(item).unwrap()
         )
-        .sum();
-    let sum_priorities = u32::try_from(sum_priorities).unwrap();
-    sum_priorities
+        .sum()
 }
 
 pub fn part_one(input: &str) -> Option<u32> {
@@ -44,8 +27,8 @@ pub fn part_one(input: &str) -> Option<u32> {
             let compartments = rucksack.split_at(rucksack.len() / 2);
 
             common_item([
-                rucksack_items_to_set(compartments.0), 
-                rucksack_items_to_set(compartments.1)
+                LetterSet::from(compartments.0), 
+                LetterSet::from(compartments.1)
             ].into_iter())
         });
     let sum_priorities = sum_priorities(rucksacks);
@@ -56,14 +39,14 @@ pub fn part_two(input: &str) -> Option<u32> {
     let rucksack_groups = input
         .lines()
         .map(
-            |rucksack| rucksack_items_to_set(rucksack)
+            LetterSet::from
         )
         .chunks(3);
 
     let rucksack_groups = rucksack_groups.into_iter();
 
     let common_items = rucksack_groups.map(
-        |group| common_item(group)
+        common_item
     );
 
     let sum_priorities = sum_priorities(common_items);
@@ -81,6 +64,8 @@ fn main() {
 mod tests {
     use super::*;
 
+    advent_of_code::test_examples!(3, part_one, part_two);
+
     #[test]
     fn test_part_one() {
         let input = advent_of_code::read_file("examples", 3);
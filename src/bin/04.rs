@@ -1,67 +1,42 @@
-use std::collections::HashSet;
+use advent_of_code::helpers::{parse_lines, Interval};
 
-fn duplicate_work<F>(input: &str, check: F) -> u32 
+fn duplicate_work<F>(input: &str, check: F) -> u32
 where
-    F: Fn(&HashSet<u32>, &HashSet<u32>) -> bool
+    F: Fn(&Interval, &Interval) -> bool
     {
-    let assignment_to_set = |assignment: &str| {
-        let assignment: Vec<_> = assignment
-            .split("-")
-            .map(
-                |boundary| 
-                boundary.parse::<u32>().unwrap()
-            )
-            .collect();
+    let pairs = parse_lines(
+        input,
+        |assignment|
+            advent_of_code::scan!(assignment, "{}-{},{}-{}", i64, i64, i64, i64)
+                .filter(
+                    |&(start_left, end_left, start_right, end_right)|
+                    start_left <= end_left && start_right <= end_right
+                )
+                .map(
+                    |(start_left, end_left, start_right, end_right)|
+                    (Interval::new(start_left, end_left), Interval::new(start_right, end_right))
+                )
+    )
+    .unwrap_or_else(|e| panic!("{e}"));
 
-        let assignment_range: Option<HashSet<u32>>;
-
-        match assignment[..] {
-            [start, end] => 
-                assignment_range = Some(
-                    HashSet::from_iter((start..=end).step_by(1))
-                ),
-            _ => 
-                assignment_range = None,
-        }
-        assignment_range.unwrap()
-    };
-
-    let duplicate_work: u32 = input
-        .lines()
-        .map(
-            |assignment| {
-                let pairs = assignment
-                    .split(",")
-                    .map(assignment_to_set)
-                    .collect::<Vec<_>>();
-
-                let result: Option<bool>;
-                match &pairs[..] {
-                    [left, right] => {
-                        result = Some(check(left, right))
-                    },
-                    _ => result = None,
-                }
-                let result = result.unwrap();
-                result as u32
-            }
-        )
-        .sum();
-    duplicate_work
+    let duplicate_work = pairs
+        .iter()
+        .filter(|(left, right)| check(left, right))
+        .count();
+    duplicate_work as u32
 }
 
 pub fn part_one(input: &str) -> Option<u32> {
-    let check = |left: &HashSet<u32>, right: &HashSet<u32>| { 
-        left.is_subset(right) || right.is_subset(left) 
+    let check = |left: &Interval, right: &Interval| {
+        left.contains_interval(right) || right.contains_interval(left)
     };
     let full_overlaps = duplicate_work(input, check);
     Some(full_overlaps)
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
-    let check = |left: &HashSet<u32>, right: &HashSet<u32>| { 
-        let overlaps = left.intersection(right);
-        if overlaps.count() > 0 { true } else { false } 
+    let check = |left: &Interval, right: &Interval| {
+        left.overlaps(right)
     };
     let partial_overlaps = duplicate_work(input, check);
     Some(partial_overlaps)
@@ -77,6 +52,8 @@ fn main() {
 mod tests {
     use super::*;
 
+    advent_of_code::test_examples!(4, part_one, part_two);
+
     #[test]
     fn test_part_one() {
         let input = advent_of_code::read_file("examples", 4);
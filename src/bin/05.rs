@@ -1,5 +1,4 @@
-use lazy_static::lazy_static;
-use regex::Regex;
+use advent_of_code::helpers::{paragraphs, parse_lines};
 
 enum CrateMover {
     CM9000,
@@ -109,30 +108,23 @@ impl SupplyStacks {
 }
 
 fn parse_rearrangements(rearrangement_input: &str) -> Vec<Rearrangement> {
-    lazy_static! {
-        static ref REARRANGEMENT_PTRN: Regex = Regex::new(
-            r"move (?P<move>\d+) from (?P<from>\d) to (?P<to>\d)"
-        ).unwrap();
-    }
-
-    rearrangement_input
-        .lines()
-        .map(
-            |rearrangement|
-            {
-                let caps = REARRANGEMENT_PTRN.captures(rearrangement).unwrap();
-                Rearrangement{
-                    move_: caps["move"].parse().unwrap(),
-                    from: caps["from"].parse().unwrap(),
-                    to: caps["to"].parse().unwrap(),
-                }
-            }
-        )
-        .collect()
+    parse_lines(
+        rearrangement_input,
+        |rearrangement|
+        {
+            let (move_, from, to) = advent_of_code::scan!(
+                rearrangement,
+                "move {} from {} to {}",
+                usize, usize, usize
+            )?;
+            Some(Rearrangement{move_, from, to})
+        }
+    )
+    .unwrap_or_else(|e| panic!("{e}"))
 }
 
 fn supply_stacks(input: &str, cm: CrateMover) -> String {
-    let mut input = input.split("\n\n");
+    let mut input = paragraphs(input);
 
     let stack_input = input.next().unwrap();
     let mut stacks = SupplyStacks::new(stack_input);
@@ -167,6 +159,8 @@ fn main() {
 mod tests {
     use super::*;
 
+    advent_of_code::test_examples!(5, part_one, part_two);
+
     #[test]
     fn test_part_one() {
         let input = advent_of_code::read_file("examples", 5);
@@ -1,12 +1,17 @@
-use std::collections::HashSet;
+use advent_of_code::helpers::LetterSet;
 
 fn detect_marker(input: &str, marker_len: usize) -> Option<u32>{
+    // a trailing line break is not part of the datastream, anything else that isn't a letter is invalid.
+    let input = input.trim_end();
+    if !input.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
     let chunks: Vec<_> = input.chars().collect();
 
     for (idx, chunk) in chunks.windows(marker_len).enumerate()
         {
             let char_no = idx + marker_len;
-            let chunk_set: HashSet<_> = chunk.iter().collect();
+            let chunk_set: LetterSet = chunk.iter().copied().collect();
             if chunk_set.len() == marker_len {
                 return Some(char_no as u32);
             }
@@ -33,29 +38,11 @@ fn main() {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        for (input, result) in [
-            ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7),
-            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5),
-            ("nppdvjthqldpwncqszvftbrmjlhg", 6),
-            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10),
-            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11),
-        ] {
-            assert_eq!(part_one(input), Some(result));
-        }
-    }
+    advent_of_code::test_examples!(6, part_one, part_two);
 
     #[test]
-    fn test_part_two() {
-        for (input, result) in [
-            ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 19),
-            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 23),
-            ("nppdvjthqldpwncqszvftbrmjlhg",23),
-            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 29),
-            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 26),
-        ] {
-            assert_eq!(part_two(input), Some(result));
-        }
+    fn test_invalid_input() {
+        assert_eq!(part_one("bvwbjplbgvbhsrlpgdmjqwftvncz\r\n"), Some(5));
+        assert_eq!(part_one("bvwb jplbgvbhsrlpgdmjqwftvncz"), None);
     }
 }
@@ -1,102 +1,19 @@
-use std::{cell::RefCell, collections::HashMap, rc::{Weak, Rc}};
+use advent_of_code::helpers::Tree;
 
-struct File {
-    size: u64,
+enum Entry {
+    Dir,
+    File { size: u64 },
 }
 
-struct DirInner {
-    subdirs: HashMap<String, Dir>,
-    files: HashMap<String, File>,
-    parent: WeakDir,
-}
-
-#[derive(Clone)]
-struct Dir(Rc<RefCell<DirInner>>);
-
-#[derive(Clone)]
-struct WeakDir(Weak<RefCell<DirInner>>);
-
-impl Dir {
-    fn new(parent: Option<&Self>) -> Self {
-        let weak_parent = match parent {
-            Some(parent) => {
-                let rc_dir = &parent.0;
-                WeakDir(Rc::downgrade(rc_dir))
-
-            },
-            None => WeakDir(Weak::new()),
-        };
-
-        Self(Rc::new(RefCell::new(DirInner {
-            subdirs: HashMap::new(), 
-            files: HashMap::new(),
-            parent: weak_parent,
-        })))
-    }
-
-    fn mkdir(&self, name: &str) {
-        let mut dir = self.0.borrow_mut();
-        dir.subdirs.insert(name.to_string(), Dir::new(Some(self)));
-    }
-
-    fn touch(&self, name: &str, size: u64) {
-        let mut dir = self.0.borrow_mut();
-        dir.files.insert(name.to_string(), File { size });
-    }
-
-    fn cd(&self, dir_name: &str) -> Dir {
-        let dir = self.0.borrow();
-
-        match dir_name {
-            ".." => {
-                let weak_parent = dir.parent.clone();
-                let parent = weak_parent.0.upgrade().unwrap();
-
-                Dir(parent)
-            }
-            _ => {
-                let subdirs = &dir.subdirs;
-                let subdir = subdirs.get(dir_name).unwrap().clone();
-                subdir
-            }
-        }
-    }
-
-    fn size(&self) -> u64 {
-        let dir = self.0.borrow();
-        let dir_sizes: u64 = dir
-            .subdirs
-            .values()
-            .map(
-                |dir| dir.size() 
-            )
-            .sum();
-        
-        let file_sizes: u64 = dir
-            .files
-            .values()
-            .map(
-                |file| file.size
-            )
-            .sum();
-
-        dir_sizes + file_sizes
-        
-    }
-
-}
-
-fn create_fs(input: &str) -> Dir {
+fn create_fs(input: &str) -> Tree<Entry> {
     let mut input = input.lines();
 
-    let fs;
-
-    match input.next() {
-        Some("$ cd /") => { fs = Dir::new(None) },
+    let mut fs = match input.next() {
+        Some("$ cd /") => Tree::new(Entry::Dir),
         _ => panic!("Unexpected input")
-    }
+    };
 
-    let mut curr_dir = fs.clone();
+    let mut curr_dir = fs.root();
     let mut ls = false;
 
     for line in input {
@@ -112,17 +29,23 @@ fn create_fs(input: &str) -> Dir {
             ["$", "cd", dir_name]
                 => {
                     ls = false;
-                    curr_dir = curr_dir.cd(dir_name);
+                    curr_dir = match dir_name {
+                        "/" => fs.root(),
+                        ".." => fs.parent(curr_dir).expect("Unexpected command"),
+                        _ => fs.child(curr_dir, dir_name).expect("Unexpected command"),
+                    };
                 }
 
-            ["dir", dir_name] if ls == true
-                => curr_dir.mkdir(dir_name),
+            ["dir", dir_name] if ls
+                => {
+                    fs.add_child(curr_dir, dir_name, Entry::Dir);
+                },
 
             [size, file_name] 
-                if ls == true
+                if ls
                 => {
                     let size: u64 = size.parse().expect("Unexpected command");
-                    curr_dir.touch(file_name, size);
+                    fs.add_child(curr_dir, file_name, Entry::File { size });
                 },
 
             _ => panic!("Unexpected command"),
@@ -133,49 +56,34 @@ fn create_fs(input: &str) -> Dir {
     fs
 }
 
-#[derive(Copy, Clone)]
-enum DirSizeComps {
-    LE,
-    GE,
-}
-
-fn dir_search(fs: Dir, size: u64, comp: DirSizeComps) -> Vec<Dir> {
-    let mut dirs: Vec<Dir> = Vec::new();
-
-    fn search(dir_: Dir, dirs: &mut Vec<Dir>, size: u64, comp: DirSizeComps)
-    {
-        let dir_clone = dir_.clone();
-        let comp_result = match comp {
-            DirSizeComps::LE => dir_.size() <= size,
-            DirSizeComps::GE => dir_.size() >= size,
-        };
-
-        if comp_result {
-            dirs.push(dir_clone)
+/// Returns the size of every directory, the root first.
+fn dir_sizes(fs: &Tree<Entry>) -> Vec<u64> {
+    let sizes = fs.fold(
+        fs.root(),
+        |entry, children| match entry {
+            Entry::Dir => children.iter().copied().sum(),
+            Entry::File { size } => *size,
         }
+    );
 
-        let dir_clone = dir_.clone();
-        let dir_inner = dir_clone.0.borrow();
-        for subdir in dir_inner.subdirs.values() {
-            let subdir_clone = subdir.clone();
-            search(subdir_clone, dirs, size, comp)
-        }
-    }
-
-    search(fs, &mut dirs, size, comp);
-    dirs
-
+    fs
+        .pre_order(fs.root())
+        .filter(
+            |&id| matches!(fs[id], Entry::Dir)
+        )
+        .map(
+            |id| sizes[&id]
+        )
+        .collect()
 }
 
 pub fn part_one(input: &str) -> Option<u64> {
     let fs = create_fs(input);
 
-    let dirs_under_100k = dir_search(fs, 100_000, DirSizeComps::LE);
-
-    let result: u64 = dirs_under_100k
-        .iter()
-        .map(
-            |dir| dir.size()
+    let result: u64 = dir_sizes(&fs)
+        .into_iter()
+        .filter(
+            |&size| size <= 100_000
         )
         .sum();
 
@@ -184,16 +92,15 @@ pub fn part_one(input: &str) -> Option<u64> {
 
 pub fn part_two(input: &str) -> Option<u64> {
     let fs = create_fs(input);
+    let dir_sizes = dir_sizes(&fs);
 
-    let size_left = 70_000_000 - fs.size();
+    let size_left = 70_000_000 - dir_sizes[0];
     let size_req = 30_000_000 - size_left;
 
-    let dirs_to_free_space = dir_search(fs, size_req, DirSizeComps::GE);
-
-    let result: u64 = dirs_to_free_space
-        .iter()
-        .map(
-            |dir| dir.size()
+    let result: u64 = dir_sizes
+        .into_iter()
+        .filter(
+            |&size| size >= size_req
         )
         .min()
         .unwrap();
@@ -211,6 +118,8 @@ fn main() {
 mod tests {
     use super::*;
 
+    advent_of_code::test_examples!(7, part_one, part_two);
+
     #[test]
     fn test_part_one() {
         let input = advent_of_code::read_file("examples", 7);
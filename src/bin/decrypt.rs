@@ -0,0 +1,65 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::crypt::{self, Key, Status};
+use std::process;
+
+struct Args {
+    day: Option<u8>,
+    force: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        force: args.contains(["-f", "--force"]),
+        day: args.opt_free_from_str()?,
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    };
+
+    let key = match Key::load() {
+        Ok(key) => key,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    let mut failed = false;
+
+    for path in crypt::list_files(true, args.day) {
+        let target = crypt::decrypted_path(&path).unwrap_or_default();
+        match crypt::decrypt_file(&key, &path, args.force) {
+            Ok(Status::Written) => {
+                println!(
+                    "Decrypted \"{}\" to \"{}\"",
+                    path.display(),
+                    target.display()
+                )
+            }
+            Ok(Status::Unchanged) => println!("\"{}\" is up to date", target.display()),
+            Ok(Status::Skipped) => println!(
+                "Skipped \"{}\": file differs from encrypted copy. Pass --force to overwrite it.",
+                target.display()
+            ),
+            Err(e) => {
+                eprintln!("Failed to decrypt \"{}\": {e}", path.display());
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
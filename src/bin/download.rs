@@ -2,22 +2,178 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::aoc_cli;
-use std::process;
+use advent_of_code::aoc_client::{self, AocClientError};
+use std::{
+    io::{self, Write},
+    process::{self, Command},
+    thread,
+    time::Duration,
+};
+
+/// Seconds to wait between two downloads when downloading several days.
+const DEFAULT_DELAY: u64 = 5;
+/// Attempts and seconds between attempts when a puzzle is not available right at unlock time.
+const UNLOCK_RETRIES: u32 = 5;
+const UNLOCK_RETRY_DELAY: u64 = 2;
+
+enum Days {
+    One(u8),
+    All,
+}
 
 struct Args {
-    day: u8,
+    days: Days,
     year: Option<u16>,
+    delay: u64,
+    force: bool,
+    wait: bool,
+    scaffold: bool,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
+    let all = args.contains(["-a", "--all"]);
+    let force = args.contains(["-f", "--force"]);
+    let wait = args.contains(["-w", "--wait"]);
+    let scaffold = args.contains(["-s", "--scaffold"]);
+    let year = args.opt_value_from_str(["-y", "--year"])?;
+    let delay = args
+        .opt_value_from_str(["-d", "--delay"])?
+        .unwrap_or(DEFAULT_DELAY);
+
+    let days = if all {
+        Days::All
+    } else {
+        Days::One(args.free_from_str()?)
+    };
+
     Ok(Args {
-        day: args.free_from_str()?,
-        year: args.opt_value_from_str(["-y", "--year"])?,
+        days,
+        year,
+        delay,
+        force,
+        wait,
+        scaffold,
     })
 }
 
+fn download_all(year: u16, delay: u64) {
+    let released = aoc_client::released_days(year);
+    if released == 0 {
+        eprintln!("No puzzles of {year} are unlocked yet.");
+        process::exit(1);
+    }
+
+    let mut downloaded = Vec::new();
+    let mut skipped = Vec::new();
+    let mut failed = Vec::new();
+
+    for day in 1..=released {
+        if aoc_client::is_downloaded(day, year) {
+            skipped.push(day);
+            continue;
+        }
+
+        // wait between requests to go easy on the servers.
+        if !(downloaded.is_empty() && failed.is_empty()) {
+            thread::sleep(Duration::from_secs(delay));
+        }
+
+        match aoc_client::download(day, Some(year), false) {
+            Ok(_) => downloaded.push(day),
+            Err(e) => {
+                eprintln!("failed to download day {day}: {e}");
+                failed.push(day);
+            }
+        }
+    }
+
+    let format_days = |days: &[u8]| {
+        if days.is_empty() {
+            "-".to_string()
+        } else {
+            days.iter()
+                .map(|day| day.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        }
+    };
+
+    println!("---");
+    println!("🎄 Downloaded: {}", format_days(&downloaded));
+    println!("🎄 Skipped (already present): {}", format_days(&skipped));
+    println!("🎄 Failed: {}", format_days(&failed));
+
+    if !failed.is_empty() {
+        process::exit(1);
+    }
+}
+
+fn format_duration(secs: i64) -> String {
+    let (days, secs) = (secs / 86_400, secs % 86_400);
+    let time = format!(
+        "{:02}:{:02}:{:02}",
+        secs / 3600,
+        secs % 3600 / 60,
+        secs % 60
+    );
+
+    if days > 0 {
+        format!("{days}d {time}")
+    } else {
+        time
+    }
+}
+
+fn wait_for_unlock(day: u8, year: u16) {
+    let unlock_time = aoc_client::unlock_time(day, year);
+
+    loop {
+        let remaining = unlock_time - aoc_client::now_secs();
+        if remaining <= 0 {
+            break;
+        }
+
+        print!(
+            "\r⏳ Day {day} of {year} unlocks in {} ",
+            format_duration(remaining)
+        );
+        io::stdout().flush().ok();
+        thread::sleep(Duration::from_secs(1));
+    }
+
+    println!("\r🔓 Day {day} of {year} is unlocked!          ");
+}
+
+/// Downloads a day that just unlocked. The site may still respond with 404 for a moment.
+fn download_unlocked(day: u8, year: u16, force: bool) -> Result<(), AocClientError> {
+    let mut attempt = 1;
+
+    loop {
+        match aoc_client::download(day, Some(year), force) {
+            Err(AocClientError::PuzzleLocked(..) | AocClientError::PuzzleNotFound(..))
+                if attempt < UNLOCK_RETRIES =>
+            {
+                eprintln!("Puzzle is not available yet, retrying...");
+                thread::sleep(Duration::from_secs(UNLOCK_RETRY_DELAY));
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+fn scaffold(day: u8) {
+    let status = Command::new("cargo")
+        .args(["scaffold", &day.to_string()])
+        .status();
+
+    if !status.is_ok_and(|status| status.success()) {
+        eprintln!("failed to scaffold day {day}.");
+        process::exit(1);
+    }
+}
+
 fn main() {
     let args = match parse_args() {
         Ok(args) => args,
@@ -27,20 +183,32 @@ fn main() {
         }
     };
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
+    match args.days {
+        Days::All => {
+            let year = args.year.unwrap_or_else(aoc_client::current_event_year);
+            download_all(year, args.delay);
+        }
+        Days::One(day) => {
+            let result = if args.wait {
+                let year = args.year.unwrap_or_else(aoc_client::current_year);
+                if day == 0 || day > aoc_client::event_days(year) {
+                    eprintln!("Day {day} is not part of the {year} event.");
+                    process::exit(1);
+                }
+                wait_for_unlock(day, year);
+                download_unlocked(day, year, args.force)
+            } else {
+                aoc_client::download(day, args.year, args.force)
+            };
 
-    match aoc_cli::download(args.day, args.year) {
-        Ok(cmd_output) => {
-            if !cmd_output.status.success() {
+            if let Err(e) = result {
+                eprintln!("failed to download puzzle: {e}");
                 process::exit(1);
             }
-        }
-        Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {e}");
-            process::exit(1);
+
+            if args.scaffold {
+                scaffold(day);
+            }
         }
     }
 }
@@ -0,0 +1,69 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::crypt::{self, Key, Status, KEY_FILE};
+use std::process;
+
+struct Args {
+    day: Option<u8>,
+    new_key: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        new_key: args.contains("--new-key"),
+        day: args.opt_free_from_str()?,
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    };
+
+    let key = if args.new_key {
+        Key::create_file().inspect(|_| {
+            println!("Created a new key in \"{KEY_FILE}\". Share it with your team through a password manager.")
+        })
+    } else {
+        Key::load()
+    };
+
+    let key = match key {
+        Ok(key) => key,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    let mut failed = false;
+
+    for path in crypt::list_files(false, args.day) {
+        let target = crypt::encrypted_path(&path);
+        match crypt::encrypt_file(&key, &path) {
+            Ok(Status::Written) => {
+                println!(
+                    "Encrypted \"{}\" to \"{}\"",
+                    path.display(),
+                    target.display()
+                )
+            }
+            Ok(_) => println!("\"{}\" is up to date", target.display()),
+            Err(e) => {
+                eprintln!("Failed to encrypt \"{}\": {e}", path.display());
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,138 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::{aoc_client, puzzle, Example};
+use std::{
+    fs,
+    io::{self, Write},
+    process,
+};
+
+struct Args {
+    day: u8,
+    block: Option<usize>,
+    name: Option<String>,
+    force: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        block: args.opt_value_from_str(["-b", "--block"])?,
+        name: args.opt_value_from_str(["-n", "--name"])?,
+        force: args.contains(["-f", "--force"]),
+        day: args.free_from_str()?,
+    })
+}
+
+fn prompt_block(count: usize) -> usize {
+    print!("Which code block is the example? [1-{count}, default: 1] ");
+    io::stdout().flush().ok();
+
+    let mut line = String::new();
+    if io::stdin().read_line(&mut line).is_err() || line.trim().is_empty() {
+        return 1;
+    }
+
+    line.trim().parse().unwrap_or(0)
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    };
+
+    let day_padded = format!("{:02}", args.day);
+
+    let markdown = match aoc_client::read(args.day, None, false) {
+        Ok(markdown) => markdown,
+        Err(e) => {
+            eprintln!("failed to read puzzle: {e}");
+            process::exit(1);
+        }
+    };
+
+    let blocks = puzzle::code_blocks(&markdown);
+    if blocks.is_empty() {
+        eprintln!("Puzzle description does not contain any code blocks.");
+        process::exit(1);
+    }
+
+    for (idx, block) in blocks.iter().enumerate() {
+        let first_line: String = block
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .chars()
+            .take(40)
+            .collect();
+        println!(
+            "[{}] {first_line} ({} lines)",
+            idx + 1,
+            block.lines().count()
+        );
+    }
+
+    let block_no = args.block.unwrap_or_else(|| prompt_block(blocks.len()));
+    let Some(block) = block_no.checked_sub(1).and_then(|idx| blocks.get(idx)) else {
+        eprintln!("Code block {block_no} does not exist.");
+        process::exit(1);
+    };
+
+    let name = match &args.name {
+        Some(name) => format!("{day_padded}-{name}"),
+        None => day_padded.clone(),
+    };
+    let example_path = format!("src/examples/{name}.txt");
+
+    let is_empty =
+        fs::read_to_string(&example_path).map_or(true, |contents| contents.trim().is_empty());
+    if !is_empty && !args.force {
+        eprintln!("Example file \"{example_path}\" is not empty. Pass --force to overwrite it.");
+        process::exit(1);
+    }
+
+    let (part_one, part_two) = puzzle::example_answers(&markdown);
+    let example = Example {
+        name,
+        input: block.clone(),
+        part_one,
+        part_two,
+    };
+
+    if let Err(e) = fs::write(&example_path, example.to_string()) {
+        eprintln!("Failed to write example file: {e}");
+        process::exit(1);
+    }
+    println!("Wrote block {block_no} to \"{example_path}\"");
+
+    let module_path = format!("src/bin/{day_padded}.rs");
+    let Ok(mut module) = fs::read_to_string(&module_path) else {
+        return;
+    };
+
+    for part in [1, 2] {
+        let Some(answer) = example.answer(part) else {
+            continue;
+        };
+        println!("Expected answer for part {part}: {answer}");
+
+        if args.name.is_some() {
+            continue;
+        }
+        if let Some(filled) = puzzle::fill_test_answer(&module, part, answer) {
+            module = filled;
+            println!("Filled answer for part {part} into \"{module_path}\"");
+        }
+    }
+
+    if let Err(e) = fs::write(&module_path, module) {
+        eprintln!("Failed to update module file: {e}");
+        process::exit(1);
+    }
+}
@@ -0,0 +1,61 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::{
+    aoc_client,
+    leaderboard::{self, LeaderboardError},
+};
+use std::{env, process};
+
+struct Args {
+    id: Option<u64>,
+    year: Option<u16>,
+    day: Option<u8>,
+    offline: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        id: args.opt_value_from_str(["-i", "--id"])?,
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        day: args.opt_value_from_str(["-d", "--day"])?,
+        offline: args.contains(["-o", "--offline"]),
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    };
+
+    let id = args.id.or_else(|| {
+        env::var(leaderboard::ID_ENV_VAR)
+            .ok()
+            .and_then(|id| id.trim().parse().ok())
+    });
+    let Some(id) = id else {
+        eprintln!("{}", LeaderboardError::MissingId);
+        process::exit(1);
+    };
+
+    let year = args.year.unwrap_or_else(aoc_client::current_event_year);
+
+    let leaderboard = match leaderboard::load(id, year, args.offline) {
+        Ok(leaderboard) => leaderboard,
+        Err(e) => {
+            eprintln!("failed to load leaderboard: {e}");
+            process::exit(1);
+        }
+    };
+
+    match args.day {
+        Some(day) => print!("{}", leaderboard::render_day(&leaderboard, day)),
+        None => print!("{}", leaderboard::render_standings(&leaderboard)),
+    }
+}
@@ -2,19 +2,23 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::aoc_cli;
-use std::process;
+use advent_of_code::{aoc_client, markdown};
+use std::{env, process};
+
+const DEFAULT_WIDTH: usize = 80;
 
 struct Args {
     day: u8,
     year: Option<u16>,
+    refresh: bool,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
     Ok(Args {
-        day: args.free_from_str()?,
+        refresh: args.contains(["-r", "--refresh"]),
         year: args.opt_value_from_str(["-y", "--year"])?,
+        day: args.free_from_str()?,
     })
 }
 
@@ -27,19 +31,15 @@ fn main() {
         }
     };
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
+    let width = env::var("COLUMNS")
+        .ok()
+        .and_then(|columns| columns.parse().ok())
+        .unwrap_or(DEFAULT_WIDTH);
 
-    match aoc_cli::read(args.day, args.year) {
-        Ok(cmd_output) => {
-            if !cmd_output.status.success() {
-                process::exit(1);
-            }
-        }
+    match aoc_client::read(args.day, args.year, args.refresh) {
+        Ok(puzzle) => print!("{}", markdown::render(&puzzle, width)),
         Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {e}");
+            eprintln!("failed to read puzzle: {e}");
             process::exit(1);
         }
     }
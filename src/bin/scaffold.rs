@@ -2,72 +2,160 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::{
+    aoc_client, crypt, puzzle,
+    template::{self, Placeholders},
+    vscode,
+};
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
     process,
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
-    None
-}
-
-pub fn part_two(input: &str) -> Option<u32> {
-    None
+struct Args {
+    day: u8,
+    template: String,
+    answer_type: String,
+    year: Option<u16>,
+    download: bool,
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+enum Command {
+    Create(Args),
+    Remove { day: u8, force: bool },
+    Move { from: u8, to: u8 },
+    Tests { day: u8, template: String },
+    Launch,
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
-    }
+fn parse_args() -> Result<Command, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let template = |args: &mut pico_args::Arguments| -> Result<String, pico_args::Error> {
+        Ok(args
+            .opt_value_from_str(["-t", "--template"])?
+            .unwrap_or_else(|| template::DEFAULT_TEMPLATE.to_string()))
+    };
 
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
+    match args.subcommand()?.as_deref() {
+        Some("remove") => Ok(Command::Remove {
+            force: args.contains(["-f", "--force"]),
+            day: args.free_from_str()?,
+        }),
+        Some("move") => Ok(Command::Move {
+            from: args.free_from_str()?,
+            to: args.free_from_str()?,
+        }),
+        Some("launch") => Ok(Command::Launch),
+        Some("tests") => Ok(Command::Tests {
+            template: template(&mut args)?,
+            day: args.free_from_str()?,
+        }),
+        day => Ok(Command::Create(Args {
+            template: template(&mut args)?,
+            answer_type: args
+                .opt_value_from_str("--type")?
+                .unwrap_or_else(|| template::DEFAULT_ANSWER_TYPE.to_string()),
+            year: args.opt_value_from_str(["-y", "--year"])?,
+            download: args.contains(["-d", "--download"]),
+            day: match day {
+                Some(day) => day
+                    .parse()
+                    .map_err(|_| pico_args::Error::ArgumentParsingFailed {
+                        cause: format!("\"{day}\" is not a day or command"),
+                    })?,
+                None => args.free_from_str()?,
+            },
+        })),
     }
 }
-"###;
-
-fn parse_args() -> Result<u8, pico_args::Error> {
-    let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
-}
 
 fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
     OpenOptions::new().write(true).create_new(true).open(path)
 }
 
 fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
 }
 
-fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
-        Err(_) => {
-            eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
-            process::exit(1);
-        }
-    };
+/// Fills the example file from the first code block of the puzzle, which is the example for
+/// nearly all puzzles. Failing to do so is not fatal, the example can be added by hand.
+fn fill_example(day: u8) {
+    let status = process::Command::new("cargo")
+        .args(["example", &day.to_string(), "--block", "1"])
+        .status();
+
+    if !status.is_ok_and(|status| status.success()) {
+        eprintln!(
+            "Could not fill the example file, run `cargo example {day}` to pick the example."
+        );
+    }
+}
+
+fn create(args: Args) {
+    let Args {
+        day,
+        template,
+        answer_type,
+        year,
+        download,
+    } = args;
 
     let day_padded = format!("{day:02}");
 
-    let input_path = format!("src/inputs/{day_padded}.txt");
+    let input_path = aoc_client::get_input_path(day);
     let example_path = format!("src/examples/{day_padded}.txt");
     let module_path = format!("src/bin/{day_padded}.rs");
 
+    // bail out before downloading anything, `safe_create_file` would only fail afterwards.
+    if Path::new(&module_path).exists() {
+        eprintln!("Failed to create module file: \"{module_path}\" already exists.");
+        process::exit(1);
+    }
+
+    let module_template = match template::load(&template) {
+        Ok(module_template) => module_template,
+        Err(e) => {
+            eprintln!("Failed to load template: {e}");
+            process::exit(1);
+        }
+    };
+
+    let Some(answer_type) = template::answer_type(&answer_type) else {
+        eprintln!(
+            "Unknown answer type \"{answer_type}\". Use one of: u32, u64, i64, usize, string."
+        );
+        process::exit(1);
+    };
+
+    let year = year.unwrap_or_else(aoc_client::current_event_year);
+
+    if download {
+        if let Err(e) = aoc_client::download(day, Some(year), false) {
+            eprintln!("Failed to download day {day}: {e}");
+            process::exit(1);
+        }
+        println!("---");
+    }
+
+    // the title is only known if the puzzle was downloaded before scaffolding.
+    let title = fs::read_to_string(aoc_client::get_puzzle_path(day))
+        .ok()
+        .and_then(|puzzle| puzzle::title(&puzzle))
+        .unwrap_or_default();
+
+    let placeholders = Placeholders {
+        day,
+        year,
+        answer_type: answer_type.to_string(),
+        title,
+    };
+
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
         Err(e) => {
@@ -76,9 +164,12 @@ fn main() {
         }
     };
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
+    match file.write_all(placeholders.render_module(&module_template).as_bytes()) {
         Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
+            println!(
+                "Created module file \"{}\" from template \"{template}\"",
+                &module_path
+            );
         }
         Err(e) => {
             eprintln!("Failed to write module contents: {e}");
@@ -86,13 +177,23 @@ fn main() {
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+    if !download {
+        // named accounts keep their inputs in a subfolder that may not exist yet.
+        if let Some(folder) = Path::new(&input_path).parent() {
+            if let Err(e) = fs::create_dir_all(folder) {
+                eprintln!("Failed to create input folder: {e}");
+                process::exit(1);
+            }
         }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
+
+        match create_file(&input_path) {
+            Ok(_) => {
+                println!("Created empty input file \"{}\"", &input_path);
+            }
+            Err(e) => {
+                eprintln!("Failed to create input file: {e}");
+                process::exit(1);
+            }
         }
     }
 
@@ -106,9 +207,222 @@ fn main() {
         }
     }
 
+    if download {
+        fill_example(day);
+    }
+
+    sync_launch();
+
     println!("---");
     println!(
         "🎄 Type `cargo solve {}` to run your solution.",
         &day_padded
     );
 }
+
+/// Keeps the debug configurations of the days in sync, if the project has a launch file.
+fn sync_launch() {
+    match vscode::sync_launch() {
+        Ok(true) => println!(
+            "Updated debug configurations in \"{}\"",
+            vscode::LAUNCH_PATH
+        ),
+        Ok(false) => {}
+        Err(e) => eprintln!("Failed to update debug configurations: {e}"),
+    }
+}
+
+/// Returns all files that belong to a day: module, inputs, examples and puzzle description,
+/// including encrypted copies and the inputs of named accounts.
+fn day_files(day: u8) -> Vec<PathBuf> {
+    let day_padded = format!("{day:02}");
+    let mut files = vec![PathBuf::from(format!("src/bin/{day_padded}.rs"))];
+
+    files.extend(crypt::list_files(false, Some(day)));
+    files.extend(crypt::list_files(true, Some(day)));
+
+    if let Ok(entries) = fs::read_dir("src/examples") {
+        files.extend(
+            entries
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|path| {
+                    let name = path.file_stem().and_then(|stem| stem.to_str());
+                    name.and_then(|name| name.strip_prefix(&day_padded))
+                        .is_some_and(|suffix| suffix.is_empty() || suffix.starts_with('-'))
+                }),
+        );
+    }
+
+    files.retain(|path| path.is_file());
+    files.sort();
+    files
+}
+
+/// Path of a day file after moving it to another day. The day is the first two characters of the file name.
+fn moved_path(path: &Path, to: u8) -> PathBuf {
+    let file_name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or_default();
+    path.with_file_name(format!("{to:02}{}", &file_name[2..]))
+}
+
+fn confirm(question: &str) -> bool {
+    print!("{question} [y/N] ");
+    io::stdout().flush().ok();
+
+    let mut line = String::new();
+    io::stdin().read_line(&mut line).is_ok() && line.trim().eq_ignore_ascii_case("y")
+}
+
+fn remove(day: u8, force: bool) {
+    let files = day_files(day);
+    if files.is_empty() {
+        eprintln!("Day {day} has no files.");
+        process::exit(1);
+    }
+
+    for path in &files {
+        println!("{}", path.display());
+    }
+    if !force && !confirm(&format!("Remove these {} files?", files.len())) {
+        println!("Nothing was removed.");
+        return;
+    }
+
+    for path in &files {
+        if let Err(e) = fs::remove_file(path) {
+            eprintln!("Failed to remove \"{}\": {e}", path.display());
+            process::exit(1);
+        }
+    }
+    sync_launch();
+    println!("🎄 Removed day {day}.");
+}
+
+fn move_day(from: u8, to: u8) {
+    let files = day_files(from);
+    if files.is_empty() {
+        eprintln!("Day {from} has no files.");
+        process::exit(1);
+    }
+
+    let existing = day_files(to);
+    if !existing.is_empty() {
+        eprintln!("Day {to} already exists, remove it first with `cargo scaffold remove {to}`.");
+        process::exit(1);
+    }
+
+    for path in &files {
+        let target = moved_path(path, to);
+        let is_module = path.parent() == Some(Path::new("src/bin"));
+
+        let result = if is_module {
+            fs::read_to_string(path)
+                .and_then(|module| fs::write(&target, template::renumber(&module, from, to)))
+                .and_then(|_| fs::remove_file(path))
+        } else {
+            fs::rename(path, &target)
+        };
+
+        if let Err(e) = result {
+            eprintln!("Failed to move \"{}\": {e}", path.display());
+            process::exit(1);
+        }
+        println!("Moved \"{}\" to \"{}\"", path.display(), target.display());
+    }
+    sync_launch();
+
+    println!("---");
+    println!("🎄 Type `cargo solve {to:02}` to run your solution.");
+}
+
+/// Regenerates the test module of a day from a template and fills in the expected answers
+/// of the example. The solution code is left untouched.
+fn regenerate_tests(day: u8, template: &str) {
+    let day_padded = format!("{day:02}");
+    let module_path = format!("src/bin/{day_padded}.rs");
+
+    let module = match fs::read_to_string(&module_path) {
+        Ok(module) => module,
+        Err(e) => {
+            eprintln!("Failed to read module file: {e}");
+            process::exit(1);
+        }
+    };
+
+    let module_template = match template::load(template) {
+        Ok(module_template) => module_template,
+        Err(e) => {
+            eprintln!("Failed to load template: {e}");
+            process::exit(1);
+        }
+    };
+
+    let placeholders = Placeholders {
+        day,
+        year: aoc_client::current_event_year(),
+        answer_type: template::module_answer_type(&module)
+            .unwrap_or_else(|| template::DEFAULT_ANSWER_TYPE.to_string()),
+        title: String::new(),
+    };
+
+    let Some(mut updated) =
+        template::replace_tests(&module, &placeholders.render(&module_template))
+    else {
+        eprintln!("\"{module_path}\" or template \"{template}\" has no test module.");
+        process::exit(1);
+    };
+
+    let example = advent_of_code::read_examples(day)
+        .into_iter()
+        .find(|example| example.name == day_padded);
+
+    for part in [1, 2] {
+        let Some(answer) = example.as_ref().and_then(|example| example.answer(part)) else {
+            continue;
+        };
+        if let Some(filled) = puzzle::fill_test_answer(&updated, part, answer) {
+            updated = filled;
+            println!("Filled answer for part {part}: {answer}");
+        }
+    }
+
+    if let Err(e) = fs::write(&module_path, updated) {
+        eprintln!("Failed to write module file: {e}");
+        process::exit(1);
+    }
+    println!("🎄 Regenerated tests of \"{module_path}\".");
+}
+
+fn main() {
+    let command = match parse_args() {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
+            process::exit(1);
+        }
+    };
+
+    match command {
+        Command::Create(args) => create(args),
+        Command::Remove { day, force } => remove(day, force),
+        Command::Move { from, to } => move_day(from, to),
+        Command::Tests { day, template } => regenerate_tests(day, &template),
+        Command::Launch => match vscode::sync_launch() {
+            Ok(true) => println!(
+                "🎄 Updated debug configurations in \"{}\".",
+                vscode::LAUNCH_PATH
+            ),
+            Ok(false) => {
+                eprintln!("\"{}\" does not exist.", vscode::LAUNCH_PATH);
+                process::exit(1);
+            }
+            Err(e) => {
+                eprintln!("Failed to update debug configurations: {e}");
+                process::exit(1);
+            }
+        },
+    }
+}
@@ -0,0 +1,128 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::aoc_client::{self, AocClientError, Client};
+use std::{io, process};
+
+enum Command {
+    Check,
+    Set(Option<String>),
+    List,
+}
+
+struct Args {
+    command: Command,
+    account: Option<String>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+
+    let command = match args.subcommand()?.as_deref() {
+        None | Some("check") => Command::Check,
+        Some("set") => Command::Set(None),
+        Some("list") => Command::List,
+        Some(command) => {
+            return Err(pico_args::Error::ArgumentParsingFailed {
+                cause: format!("unknown command \"{command}\""),
+            })
+        }
+    };
+
+    let account = args.opt_value_from_str(["-a", "--account"])?;
+
+    let command = match command {
+        Command::Set(_) => Command::Set(args.opt_free_from_str()?),
+        command => command,
+    };
+
+    Ok(Args { command, account })
+}
+
+fn check(account: Option<&str>) -> Result<(), AocClientError> {
+    let session = aoc_client::read_session(account)?;
+    let client = Client::new(&session);
+    client.check()?;
+    let user = client.check_session()?;
+    println!(
+        "🎄 Session of account \"{}\" is valid, logged in as {user}.",
+        account.unwrap_or("default")
+    );
+    Ok(())
+}
+
+fn set(account: Option<&str>, session: Option<String>) -> Result<(), AocClientError> {
+    let session = match session {
+        Some(session) => session,
+        None => {
+            println!("Paste the value of the \"session\" cookie and press enter:");
+            let mut session = String::new();
+            io::stdin()
+                .read_line(&mut session)
+                .map_err(|_| AocClientError::IoError)?;
+            session
+        }
+    };
+
+    if session.trim().is_empty() {
+        return Err(AocClientError::SessionNotFound(account.map(str::to_string)));
+    }
+
+    let path = aoc_client::write_session(account, &session)?;
+    println!("🎄 Saved session to \"{}\".", path.display());
+
+    // validating is best effort, the cookie is kept even if the site can't be reached.
+    match Client::new(&session).check_session() {
+        Ok(user) => println!("🎄 Logged in as {user}."),
+        Err(e) => eprintln!("Could not validate session: {e}"),
+    }
+    Ok(())
+}
+
+fn list(active: Option<&str>) {
+    let marker = |account: Option<&str>| if account == active { "*" } else { " " };
+
+    if aoc_client::read_session(None).is_ok() {
+        println!("{} default", marker(None));
+    }
+    for account in aoc_client::list_accounts() {
+        println!("{} {account}", marker(Some(&account)));
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    };
+
+    let account = match args.account {
+        Some(account) => Some(account),
+        None => match aoc_client::account() {
+            Ok(account) => account,
+            Err(e) => {
+                eprintln!("{e}");
+                process::exit(1);
+            }
+        },
+    };
+    let account = account.as_deref();
+
+    let result = match args.command {
+        Command::Check => check(account),
+        Command::Set(session) => set(account, session),
+        Command::List => {
+            list(account);
+            Ok(())
+        }
+    };
+
+    if let Err(e) = result {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+}
@@ -0,0 +1,81 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::{
+    aoc_client,
+    stars::{self, Answers},
+};
+use std::process;
+
+enum Command {
+    Update,
+    Record {
+        day: u8,
+        part: u8,
+        answer: String,
+        year: Option<u16>,
+    },
+}
+
+fn parse_args() -> Result<Command, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+
+    match args.subcommand()?.as_deref() {
+        None => Ok(Command::Update),
+        Some("record") => Ok(Command::Record {
+            year: args.opt_value_from_str(["-y", "--year"])?,
+            day: args.free_from_str()?,
+            part: args.free_from_str()?,
+            answer: args.free_from_str()?,
+        }),
+        Some(command) => Err(pico_args::Error::ArgumentParsingFailed {
+            cause: format!("unknown command \"{command}\""),
+        }),
+    }
+}
+
+fn main() {
+    let command = match parse_args() {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    };
+
+    let mut answers = match Answers::load() {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    if let Command::Record {
+        day,
+        part,
+        answer,
+        year,
+    } = command
+    {
+        if !matches!(part, 1 | 2) {
+            eprintln!("Part needs to be 1 or 2.");
+            process::exit(1);
+        }
+
+        let year = year.unwrap_or_else(aoc_client::current_event_year);
+        answers.record(year, day, part, &answer);
+        if let Err(e) = answers.save() {
+            eprintln!("Failed to save answers: {e}");
+            process::exit(1);
+        }
+        println!("Recorded answer for day {day}, part {part} of {year}.");
+    }
+
+    if let Err(e) = stars::write_readme(&answers) {
+        eprintln!("Failed to update readme: {e}");
+        process::exit(1);
+    }
+    println!("🎄 Updated progress in \"{}\".", stars::README_PATH);
+}
@@ -0,0 +1,81 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::{
+    aoc_client::{self, Client, Submission},
+    stars::{self, Answers},
+};
+use std::process;
+
+struct Args {
+    day: u8,
+    part: u8,
+    answer: String,
+    year: Option<u16>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        day: args.free_from_str()?,
+        part: args.free_from_str()?,
+        answer: args.free_from_str()?,
+    })
+}
+
+/// Records an accepted answer and updates the progress table in the readme.
+fn record_answer(year: u16, day: u8, part: u8, answer: &str) {
+    let result = Answers::load().and_then(|mut answers| {
+        answers.record(year, day, part, answer);
+        answers.save()?;
+        stars::write_readme(&answers)
+    });
+
+    match result {
+        Ok(_) => println!("🎄 Recorded answer and updated \"{}\".", stars::README_PATH),
+        Err(e) => eprintln!("Failed to record answer: {e}"),
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            eprintln!("example: `cargo submit 1 2 45000`");
+            process::exit(1);
+        }
+    };
+
+    if !matches!(args.part, 1 | 2) {
+        eprintln!("Part needs to be 1 or 2.");
+        process::exit(1);
+    }
+
+    let year = args.year.unwrap_or_else(aoc_client::current_event_year);
+
+    let result = Client::from_env()
+        .and_then(|client| client.submit(args.day, year, args.part, &args.answer));
+
+    match result {
+        Ok(Submission::Correct) => {
+            println!("🎄 That's the right answer!");
+            record_answer(year, args.day, args.part, &args.answer);
+        }
+        Ok(Submission::Incorrect) => println!("That's not the right answer."),
+        Ok(Submission::TooHigh) => println!("That's not the right answer: too high."),
+        Ok(Submission::TooLow) => println!("That's not the right answer: too low."),
+        Ok(Submission::WrongLevel) => {
+            println!("This part is already solved or not unlocked yet.")
+        }
+        Ok(Submission::TooRecent(message) | Submission::Unknown(message)) => {
+            println!("{}", message.trim())
+        }
+        Err(e) => {
+            eprintln!("failed to submit answer: {e}");
+            process::exit(1);
+        }
+    }
+}
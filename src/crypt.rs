@@ -0,0 +1,312 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    env,
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use chacha20poly1305::{
+    aead::{Aead, KeyInit, OsRng},
+    ChaCha20Poly1305, Nonce,
+};
+use sha2::{Digest, Sha256};
+
+/// Environment variable that holds the encryption key, 32 random bytes encoded as 64 hex characters.
+pub const KEY_ENV_VAR: &str = "AOC_KEY";
+/// Untracked file in the project root that holds the encryption key, in the same format.
+pub const KEY_FILE: &str = ".aoc-key";
+/// File extension appended to encrypted files, e.g. `src/inputs/01.txt.enc`.
+pub const EXTENSION: &str = "enc";
+/// Folders below `src/` that contain files which should not be published.
+pub const FOLDERS: [&str; 2] = ["inputs", "puzzles"];
+
+const MAGIC: &[u8] = b"AOCENC1\n";
+const NONCE_LEN: usize = 12;
+
+pub enum CryptError {
+    MissingKey,
+    /// The key is not 64 hex characters.
+    BadKey,
+    /// A key file already exists and would be replaced.
+    KeyExists,
+    BadFormat,
+    DecryptionFailed,
+    IoError,
+}
+
+impl Display for CryptError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CryptError::MissingKey => write!(
+                f,
+                "no key found. Set the {KEY_ENV_VAR} environment variable, or run `cargo encrypt --new-key` to create a {KEY_FILE} file."
+            ),
+            CryptError::BadKey => write!(
+                f,
+                "key must be 64 hexadecimal characters (32 random bytes). Run `cargo encrypt --new-key` to create one."
+            ),
+            CryptError::KeyExists => write!(
+                f,
+                "{KEY_FILE} already exists. Remove it first if you really want to replace the key, files encrypted with it can't be decrypted anymore."
+            ),
+            CryptError::BadFormat => write!(f, "file is not an encrypted puzzle file."),
+            CryptError::DecryptionFailed => {
+                write!(f, "could not decrypt file. Is the key correct?")
+            }
+            CryptError::IoError => write!(f, "could not read or write file."),
+        }
+    }
+}
+
+pub struct Key([u8; 32]);
+
+impl Key {
+    /// Creates a random key. Keys are not derived from passphrases, as the encrypted files are public
+    /// and a weak passphrase could be guessed offline.
+    pub fn generate() -> Self {
+        Self(ChaCha20Poly1305::generate_key(&mut OsRng).into())
+    }
+
+    /// Parses a key from its hex encoding. Surrounding whitespace is ignored.
+    pub fn from_hex(hex: &str) -> Result<Self, CryptError> {
+        let hex = hex.trim();
+        if hex.len() != 64 || !hex.is_ascii() {
+            return Err(CryptError::BadKey);
+        }
+
+        let mut key = [0; 32];
+        for (byte, digits) in key.iter_mut().zip(hex.as_bytes().chunks(2)) {
+            let digits = std::str::from_utf8(digits).map_err(|_| CryptError::BadKey)?;
+            *byte = u8::from_str_radix(digits, 16).map_err(|_| CryptError::BadKey)?;
+        }
+        Ok(Self(key))
+    }
+
+    pub fn to_hex(&self) -> String {
+        self.0.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    /// Loads the key from the environment, falling back to the key file.
+    pub fn load() -> Result<Self, CryptError> {
+        let hex = match env::var(KEY_ENV_VAR) {
+            Ok(hex) => hex,
+            Err(_) => fs::read_to_string(KEY_FILE).map_err(|_| CryptError::MissingKey)?,
+        };
+
+        if hex.trim().is_empty() {
+            return Err(CryptError::MissingKey);
+        }
+
+        Self::from_hex(&hex)
+    }
+
+    /// Generates a new key and stores it in the key file, which must not exist yet.
+    pub fn create_file() -> Result<Self, CryptError> {
+        if Path::new(KEY_FILE).exists() {
+            return Err(CryptError::KeyExists);
+        }
+
+        let key = Self::generate();
+        fs::write(KEY_FILE, format!("{}\n", key.to_hex())).map_err(|_| CryptError::IoError)?;
+        Ok(key)
+    }
+
+    fn cipher(&self) -> ChaCha20Poly1305 {
+        ChaCha20Poly1305::new((&self.0).into())
+    }
+
+    /// The nonce is derived from key and plaintext, so encrypting an unchanged file
+    /// yields identical output and does not show up as a change in git.
+    fn nonce(&self, plaintext: &[u8]) -> [u8; NONCE_LEN] {
+        let digest = Sha256::new()
+            .chain_update(self.0)
+            .chain_update(plaintext)
+            .finalize();
+        let mut nonce = [0; NONCE_LEN];
+        nonce.copy_from_slice(&digest[..NONCE_LEN]);
+        nonce
+    }
+}
+
+pub fn encrypt(key: &Key, plaintext: &[u8]) -> Vec<u8> {
+    let nonce = key.nonce(plaintext);
+    let ciphertext = key
+        .cipher()
+        .encrypt(Nonce::from_slice(&nonce), plaintext)
+        .expect("encryption does not fail for in-memory buffers");
+
+    [MAGIC, &nonce, &ciphertext].concat()
+}
+
+pub fn decrypt(key: &Key, data: &[u8]) -> Result<Vec<u8>, CryptError> {
+    let data = data.strip_prefix(MAGIC).ok_or(CryptError::BadFormat)?;
+    if data.len() < NONCE_LEN {
+        return Err(CryptError::BadFormat);
+    }
+
+    let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+    key.cipher()
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| CryptError::DecryptionFailed)
+}
+
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut file_name = path.file_name().unwrap_or_default().to_owned();
+    file_name.push(".");
+    file_name.push(EXTENSION);
+    path.with_file_name(file_name)
+}
+
+pub fn decrypted_path(path: &Path) -> Option<PathBuf> {
+    if path.extension()? != EXTENSION {
+        return None;
+    }
+    Some(path.with_extension(""))
+}
+
+/// Reads the encrypted counterpart of `path`, if there is one.
+pub fn read_encrypted(path: &Path) -> Result<String, CryptError> {
+    let data = fs::read(encrypted_path(path)).map_err(|_| CryptError::IoError)?;
+    let plaintext = decrypt(&Key::load()?, &data)?;
+    String::from_utf8(plaintext).map_err(|_| CryptError::BadFormat)
+}
+
+/// Outcome of encrypting or decrypting a single file.
+pub enum Status {
+    Written,
+    Unchanged,
+    Skipped,
+}
+
+pub fn encrypt_file(key: &Key, path: &Path) -> Result<Status, CryptError> {
+    let plaintext = fs::read(path).map_err(|_| CryptError::IoError)?;
+    let target = encrypted_path(path);
+    let data = encrypt(key, &plaintext);
+
+    if fs::read(&target).is_ok_and(|existing| existing == data) {
+        return Ok(Status::Unchanged);
+    }
+
+    fs::write(&target, data).map_err(|_| CryptError::IoError)?;
+    Ok(Status::Written)
+}
+
+/// Decrypts `path`. Existing plaintext files that differ are only replaced if `force` is set.
+pub fn decrypt_file(key: &Key, path: &Path, force: bool) -> Result<Status, CryptError> {
+    let target = decrypted_path(path).ok_or(CryptError::BadFormat)?;
+    let data = fs::read(path).map_err(|_| CryptError::IoError)?;
+    let plaintext = decrypt(key, &data)?;
+
+    match fs::read(&target) {
+        Ok(existing) if existing == plaintext => return Ok(Status::Unchanged),
+        Ok(_) if !force => return Ok(Status::Skipped),
+        _ => {}
+    }
+
+    fs::write(&target, plaintext).map_err(|_| CryptError::IoError)?;
+    Ok(Status::Written)
+}
+
+/// Lists files in the protected folders, either plaintext or encrypted ones.
+/// If `day` is set, only files belonging to that day are returned.
+pub fn list_files(encrypted: bool, day: Option<u8>) -> Vec<PathBuf> {
+    let read_dir = |dir: &Path| -> Vec<PathBuf> {
+        fs::read_dir(dir).map_or(Vec::new(), |entries| {
+            entries
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .collect()
+        })
+    };
+
+    let mut files: Vec<_> = FOLDERS
+        .iter()
+        .flat_map(|folder| read_dir(&Path::new("src").join(folder)))
+        // named accounts keep their inputs in subfolders.
+        .flat_map(|path| {
+            if path.is_dir() {
+                read_dir(&path)
+            } else {
+                vec![path]
+            }
+        })
+        .filter(|path| {
+            let extension = path.extension().and_then(|ext| ext.to_str());
+            let is_encrypted = extension == Some(EXTENSION);
+            // skip backups and other files that happen to live in the folders.
+            let is_puzzle_file = is_encrypted || matches!(extension, Some("txt" | "md"));
+            let is_day_file = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.get(..2))
+                .and_then(|name| name.parse::<u8>().ok())
+                .is_some_and(|file_day| day.is_none_or(|day| day == file_day));
+            path.is_file() && is_puzzle_file && is_day_file && is_encrypted == encrypted
+        })
+        .collect();
+
+    files.sort();
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        let key = Key::generate();
+        let encrypted = encrypt(&key, b"1000\n2000");
+
+        assert!(encrypted.starts_with(MAGIC));
+        assert_eq!(encrypted, encrypt(&key, b"1000\n2000"));
+        assert_eq!(decrypt(&key, &encrypted).ok(), Some(b"1000\n2000".to_vec()));
+
+        let other_key = Key::generate();
+        assert!(matches!(
+            decrypt(&other_key, &encrypted),
+            Err(CryptError::DecryptionFailed)
+        ));
+        assert!(matches!(
+            decrypt(&key, b"1000\n2000"),
+            Err(CryptError::BadFormat)
+        ));
+    }
+
+    #[test]
+    fn test_key() {
+        let key = Key::generate();
+        assert_eq!(key.to_hex().len(), 64);
+        assert_eq!(
+            Key::from_hex(&format!(" {}\n", key.to_hex()))
+                .ok()
+                .map(|key| key.0),
+            Some(key.0)
+        );
+        assert_ne!(Key::generate().0, key.0);
+
+        assert!(matches!(Key::from_hex("hunter2"), Err(CryptError::BadKey)));
+        assert!(matches!(
+            Key::from_hex(&"g".repeat(64)),
+            Err(CryptError::BadKey)
+        ));
+        assert!(matches!(
+            Key::from_hex(&"ä".repeat(32)),
+            Err(CryptError::BadKey)
+        ));
+    }
+
+    #[test]
+    fn test_paths() {
+        let path = Path::new("src/inputs/01.txt");
+        assert_eq!(encrypted_path(path), PathBuf::from("src/inputs/01.txt.enc"));
+        assert_eq!(
+            decrypted_path(&encrypted_path(path)),
+            Some(path.to_path_buf())
+        );
+        assert_eq!(decrypted_path(path), None);
+    }
+}
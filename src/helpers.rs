@@ -2,3 +2,16 @@
  * Use this file if you want to extract helpers from your solutions.
  * Example import from this file: `use advent_of_code::helpers::example_fn;`.
  */
+mod grid;
+mod letters;
+mod parse;
+mod range;
+mod search;
+mod tree;
+
+pub use grid::{Direction, Grid, GridError, Point};
+pub use letters::{letter, priority, LetterSet};
+pub use parse::{ints, paragraphs, parse_lines, scan, ParseError};
+pub use range::{Interval, RangeSet};
+pub use search::{astar, bfs, dijkstra, reachable, Path};
+pub use tree::{NodeId, Tree};
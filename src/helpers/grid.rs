@@ -0,0 +1,407 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+/// A position in a [`Grid`]. `x` is the column, `y` the row, counted from the top left corner.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Point {
+    pub fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns the adjacent point in `direction`, `None` if it would leave the top or left edge.
+    pub fn step(self, direction: Direction) -> Option<Self> {
+        let (dx, dy) = direction.delta();
+        Some(Self {
+            x: self.x.checked_add_signed(dx)?,
+            y: self.y.checked_add_signed(dy)?,
+        })
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// The four orthogonal directions, clockwise starting at [`Direction::Up`].
+    pub const ORTHOGONAL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    /// All eight directions, clockwise starting at [`Direction::Up`].
+    pub const ALL: [Self; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    /// Change of `(x, y)` when moving one step in this direction.
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Self::Up => (0, -1),
+            Self::UpRight => (1, -1),
+            Self::Right => (1, 0),
+            Self::DownRight => (1, 1),
+            Self::Down => (0, 1),
+            Self::DownLeft => (-1, 1),
+            Self::Left => (-1, 0),
+            Self::UpLeft => (-1, -1),
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turn_right().turn_right().turn_right().turn_right()
+    }
+
+    /// Turns 45 degrees clockwise.
+    pub fn turn_right(self) -> Self {
+        let idx = Self::ALL.iter().position(|&dir| dir == self).unwrap();
+        Self::ALL[(idx + 1) % 8]
+    }
+
+    /// Turns 45 degrees counterclockwise.
+    pub fn turn_left(self) -> Self {
+        let idx = Self::ALL.iter().position(|&dir| dir == self).unwrap();
+        Self::ALL[(idx + 7) % 8]
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum GridError {
+    /// A line has a different length than the first one. Holds the 1-based line number.
+    Ragged(usize),
+    /// A character could not be converted to a cell. Holds the 1-based line and column numbers.
+    BadCell(usize, usize, char),
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridError::Ragged(line) => {
+                write!(f, "line {line} has a different length than the first line.")
+            }
+            GridError::BadCell(line, column, ch) => {
+                write!(
+                    f,
+                    "unexpected character '{ch}' at line {line}, column {column}."
+                )
+            }
+        }
+    }
+}
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl Grid<char> {
+    /// Parses a grid of characters, one row per line.
+    pub fn parse(input: &str) -> Result<Self, GridError> {
+        Self::parse_with(input, Some)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from its cells, row by row.
+    ///
+    /// # Panics
+    ///
+    /// If the number of cells is not `width * height`.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "grid has the wrong number of cells"
+        );
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    /// Parses a grid, one row per line, converting every character with `parse_cell`.
+    pub fn parse_with(
+        input: &str,
+        mut parse_cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, GridError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for (y, line) in input.lines().enumerate() {
+            let line = line.trim_end_matches('\r');
+            let mut line_width = 0;
+
+            for (x, ch) in line.chars().enumerate() {
+                let cell = parse_cell(ch).ok_or(GridError::BadCell(y + 1, x + 1, ch))?;
+                cells.push(cell);
+                line_width += 1;
+            }
+
+            if *width.get_or_insert(line_width) != line_width {
+                return Err(GridError::Ragged(y + 1));
+            }
+            height += 1;
+        }
+
+        Ok(Self::new(width.unwrap_or_default(), height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[point.y * self.width + point.x])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.contains(point)
+            .then(|| &mut self.cells[point.y * self.width + point.x])
+    }
+
+    /// All points of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// All cells of the grid with their position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// Position of the first cell, row by row, that matches `predicate`.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+    }
+
+    /// Neighbors of `point` in `directions` that lie inside the grid.
+    pub fn neighbors<'a>(
+        &'a self,
+        point: Point,
+        directions: &'a [Direction],
+    ) -> impl Iterator<Item = Point> + 'a {
+        directions
+            .iter()
+            .filter_map(move |&direction| point.step(direction))
+            .filter(|&neighbor| self.contains(neighbor))
+    }
+
+    /// The up to four orthogonal neighbors of `point`.
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbors(point, &Direction::ORTHOGONAL)
+    }
+
+    /// The up to eight neighbors of `point`, including diagonal ones.
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbors(point, &Direction::ALL)
+    }
+
+    /// Cells from `point` (exclusive) to the edge of the grid in `direction`.
+    pub fn ray(&self, point: Point, direction: Direction) -> impl Iterator<Item = (Point, &T)> {
+        let mut current = Some(point);
+        std::iter::from_fn(move || {
+            let next = current?.step(direction).filter(|&next| self.contains(next));
+            current = next;
+            next.map(|next| (next, &self[next]))
+        })
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "column {x} is out of bounds");
+        (0..self.height).map(move |y| &self.cells[y * self.width + x])
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Mirrors the grid along its main diagonal: rows become columns.
+    pub fn transpose(&self) -> Self {
+        let cells = self.columns().flat_map(|column| column.cloned()).collect();
+        Self::new(self.height, self.width, cells)
+    }
+
+    /// Rotates the grid by 90 degrees clockwise.
+    pub fn rotate_right(&self) -> Self {
+        let cells = (0..self.width)
+            .flat_map(|x| self.column(x).rev().cloned())
+            .collect();
+        Self::new(self.height, self.width, cells)
+    }
+
+    /// Rotates the grid by 90 degrees counterclockwise.
+    pub fn rotate_left(&self) -> Self {
+        let cells = (0..self.width)
+            .rev()
+            .flat_map(|x| self.column(x).cloned())
+            .collect();
+        Self::new(self.height, self.width, cells)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} is out of bounds"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is out of bounds"))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "abc\ndef\n";
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse(INPUT).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(1, 1)], 'e');
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.to_string(), "abc\ndef");
+
+        let digits = Grid::parse_with("12\r\n34", |ch| ch.to_digit(10)).unwrap();
+        assert_eq!(digits.row(1), &[3, 4]);
+
+        assert_eq!(Grid::parse("ab\nc"), Err(GridError::Ragged(2)));
+        assert_eq!(
+            Grid::parse_with("12\n3x", |ch| ch.to_digit(10)),
+            Err(GridError::BadCell(2, 2, 'x'))
+        );
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::parse(INPUT).unwrap();
+        let corner = Point::new(0, 0);
+
+        assert_eq!(
+            grid.neighbors4(corner).collect::<Vec<_>>(),
+            vec![Point::new(1, 0), Point::new(0, 1)]
+        );
+        assert_eq!(grid.neighbors8(corner).count(), 3);
+        assert_eq!(grid.neighbors8(Point::new(1, 0)).count(), 5);
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = Grid::parse(INPUT).unwrap();
+
+        let cells: String = grid
+            .ray(Point::new(0, 0), Direction::Right)
+            .map(|(_, ch)| ch)
+            .collect();
+        assert_eq!(cells, "bc");
+        assert_eq!(grid.ray(Point::new(2, 1), Direction::DownRight).count(), 0);
+        assert_eq!(
+            grid.ray(Point::new(2, 1), Direction::UpLeft)
+                .collect::<Vec<_>>(),
+            vec![(Point::new(1, 0), &'b')]
+        );
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = Grid::parse(INPUT).unwrap();
+
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_right().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_left().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+        assert_eq!(grid.position(|&ch| ch == 'e'), Some(Point::new(1, 1)));
+    }
+
+    #[test]
+    fn test_direction() {
+        assert_eq!(Direction::Up.opposite(), Direction::Down);
+        assert_eq!(Direction::UpLeft.turn_right(), Direction::Up);
+        assert_eq!(Direction::Up.turn_left(), Direction::UpLeft);
+        assert_eq!(Point::new(0, 0).step(Direction::Left), None);
+        assert_eq!(Point::new(1, 2).manhattan_distance(Point::new(3, 0)), 4);
+    }
+}
@@ -0,0 +1,179 @@
+use std::{
+    fmt::Debug,
+    ops::{BitAnd, BitOr},
+};
+
+/// The priority of an ASCII letter: `a` through `z` are 1 through 26, `A` through `Z` are 27 through 52.
+pub fn priority(letter: char) -> Option<u32> {
+    match letter {
+        'a'..='z' => Some(letter as u32 - 'a' as u32 + 1),
+        'A'..='Z' => Some(letter as u32 - 'A' as u32 + 27),
+        _ => None,
+    }
+}
+
+/// The letter with the given [`priority`].
+pub fn letter(priority: u32) -> Option<char> {
+    match priority {
+        1..=26 => char::from_u32('a' as u32 + priority - 1),
+        27..=52 => char::from_u32('A' as u32 + priority - 27),
+        _ => None,
+    }
+}
+
+/// A set of ASCII letters, stored as a bitmask in which bit `n` stands for the letter with priority `n`.
+/// Cheap to copy and to combine, e.g. to find the letters that several strings have in common.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct LetterSet(u64);
+
+impl LetterSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn bit(letter: char) -> u64 {
+        match priority(letter) {
+            Some(priority) => 1 << priority,
+            None => panic!("'{}' is not an ASCII letter", letter.escape_default()),
+        }
+    }
+
+    /// Adds `letter` and returns whether it was not in the set yet.
+    ///
+    /// # Panics
+    ///
+    /// If `letter` is not an ASCII letter.
+    pub fn insert(&mut self, letter: char) -> bool {
+        let bit = Self::bit(letter);
+        let is_new = self.0 & bit == 0;
+        self.0 |= bit;
+        is_new
+    }
+
+    pub fn remove(&mut self, letter: char) -> bool {
+        let Some(priority) = priority(letter) else {
+            return false;
+        };
+        let bit = 1 << priority;
+        let was_present = self.0 & bit != 0;
+        self.0 &= !bit;
+        was_present
+    }
+
+    pub fn contains(&self, letter: char) -> bool {
+        priority(letter).is_some_and(|priority| self.0 & (1 << priority) != 0)
+    }
+
+    pub fn intersection(self, other: LetterSet) -> LetterSet {
+        LetterSet(self.0 & other.0)
+    }
+
+    pub fn union(self, other: LetterSet) -> LetterSet {
+        LetterSet(self.0 | other.0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// The letters in the set, ordered by priority.
+    pub fn iter(&self) -> impl Iterator<Item = char> {
+        let bits = self.0;
+        (1..=52)
+            .filter(move |priority| bits & (1 << priority) != 0)
+            .filter_map(letter)
+    }
+}
+
+impl From<&str> for LetterSet {
+    /// # Panics
+    ///
+    /// If the string contains anything but ASCII letters.
+    fn from(letters: &str) -> Self {
+        letters.chars().collect()
+    }
+}
+
+impl FromIterator<char> for LetterSet {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        let mut set = LetterSet::new();
+        for letter in iter {
+            set.insert(letter);
+        }
+        set
+    }
+}
+
+impl BitAnd for LetterSet {
+    type Output = LetterSet;
+
+    fn bitand(self, other: LetterSet) -> LetterSet {
+        self.intersection(other)
+    }
+}
+
+impl BitOr for LetterSet {
+    type Output = LetterSet;
+
+    fn bitor(self, other: LetterSet) -> LetterSet {
+        self.union(other)
+    }
+}
+
+impl Debug for LetterSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_priority() {
+        assert_eq!(priority('a'), Some(1));
+        assert_eq!(priority('z'), Some(26));
+        assert_eq!(priority('A'), Some(27));
+        assert_eq!(priority('Z'), Some(52));
+        assert_eq!(priority('1'), None);
+
+        for priority in 1..=52 {
+            assert_eq!(letter(priority).and_then(super::priority), Some(priority));
+        }
+        assert_eq!(letter(0), None);
+        assert_eq!(letter(53), None);
+    }
+
+    #[test]
+    fn test_letter_set() {
+        let mut set = LetterSet::from("vJrwpWtwJgWr");
+        assert_eq!(set.len(), 8);
+        assert!(set.contains('J'));
+        assert!(!set.contains('j'));
+        assert!(!set.contains('?'));
+        assert_eq!(set.iter().collect::<String>(), "gprtvwJW");
+
+        assert!(set.insert('a'));
+        assert!(!set.insert('a'));
+        assert!(set.remove('a'));
+        assert!(!set.remove('a'));
+
+        let other = LetterSet::from("hcsFMMfFFhFp");
+        assert_eq!((set & other).iter().collect::<String>(), "p");
+        assert_eq!(set.intersection(other), set & other);
+        assert_eq!((set | other).len(), 14);
+        assert!(LetterSet::new().is_empty());
+        assert_eq!(format!("{:?}", LetterSet::from("ba")), "{'a', 'b'}");
+    }
+
+    #[test]
+    #[should_panic(expected = "'?' is not an ASCII letter")]
+    fn test_insert_non_letter() {
+        LetterSet::new().insert('?');
+    }
+}
@@ -0,0 +1,210 @@
+use std::{fmt::Display, str::FromStr};
+
+/// A line of the input that could not be parsed.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number, relative to the parsed input.
+    pub line: usize,
+    pub text: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "could not parse line {}: \"{}\"", self.line, self.text)
+    }
+}
+
+/// Parses every line of `input` with `parse_line`. Fails with the first line it returns `None` for.
+pub fn parse_lines<'a, T>(
+    input: &'a str,
+    mut parse_line: impl FnMut(&'a str) -> Option<T>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            let line = line.trim_end_matches('\r');
+            parse_line(line).ok_or_else(|| ParseError {
+                line: idx + 1,
+                text: line.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Extracts all integers from `line`, e.g. `[3, 1, 2]` from `move 3 from 1 to 2`.
+/// A `-` is only read as a sign if it does not follow a letter or digit, so `2-4` yields `[2, 4]`.
+///
+/// # Panics
+///
+/// If a number does not fit into `T`.
+pub fn ints<T: FromStr>(line: &str) -> Vec<T> {
+    let bytes = line.as_bytes();
+    let mut numbers = Vec::new();
+    let mut idx = 0;
+
+    while idx < bytes.len() {
+        if !bytes[idx].is_ascii_digit() {
+            idx += 1;
+            continue;
+        }
+
+        let mut start = idx;
+        let is_negative = start > 0
+            && bytes[start - 1] == b'-'
+            && (start < 2 || !bytes[start - 2].is_ascii_alphanumeric());
+        if is_negative {
+            start -= 1;
+        }
+
+        while idx < bytes.len() && bytes[idx].is_ascii_digit() {
+            idx += 1;
+        }
+
+        let number = &line[start..idx];
+        match number.parse() {
+            Ok(number) => numbers.push(number),
+            Err(_) => panic!("\"{number}\" in \"{line}\" is out of range"),
+        }
+    }
+
+    numbers
+}
+
+/// Matches `line` against `pattern`, in which every `{}` stands for a value, and returns the values.
+/// Placeholders have to be separated by some text, e.g. `move {} from {} to {}`.
+pub fn scan<'a>(pattern: &str, line: &'a str) -> Option<Vec<&'a str>> {
+    let mut literals = pattern.split("{}");
+    let mut rest = line.strip_prefix(literals.next()?)?;
+    let literals: Vec<_> = literals.collect();
+    let mut values = Vec::with_capacity(literals.len());
+
+    for (idx, literal) in literals.iter().enumerate() {
+        let value = if idx + 1 == literals.len() {
+            let value = rest.strip_suffix(literal)?;
+            rest = "";
+            value
+        } else {
+            let end = rest.find(literal)?;
+            let value = &rest[..end];
+            rest = &rest[end + literal.len()..];
+            value
+        };
+        values.push(value);
+    }
+
+    rest.is_empty().then_some(values)
+}
+
+/// Matches a line against a pattern like [`scan`] and parses the values into the given types.
+/// Evaluates to `None` if the line does not match or a value can't be parsed.
+///
+/// ```
+/// let rearrangement = advent_of_code::scan!("move 3 from 1 to 2", "move {} from {} to {}", usize, usize, usize);
+/// assert_eq!(rearrangement, Some((3, 1, 2)));
+/// ```
+#[macro_export]
+macro_rules! scan {
+    ($line:expr, $pattern:expr, $($ty:ty),+ $(,)?) => {
+        $crate::helpers::scan($pattern, $line).and_then(|values| {
+            let mut values = values.into_iter();
+            let parsed = ($(values.next()?.trim().parse::<$ty>().ok()?,)+);
+            values.next().is_none().then_some(parsed)
+        })
+    };
+}
+
+/// Splits `input` into paragraphs, i.e. groups of lines separated by one or more blank lines.
+/// Tolerates `\r\n` line endings and whitespace-only separator lines.
+pub fn paragraphs(input: &str) -> impl Iterator<Item = &str> {
+    let mut paragraphs = Vec::new();
+    let mut start = None;
+    let mut end = 0;
+    let mut offset = 0;
+
+    for line in input.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            if let Some(start) = start.take() {
+                paragraphs.push(&input[start..end]);
+            }
+        } else {
+            start.get_or_insert(offset);
+            end = offset + line.trim_end_matches(['\r', '\n']).len();
+        }
+        offset += line.len();
+    }
+
+    if let Some(start) = start {
+        paragraphs.push(&input[start..end]);
+    }
+
+    paragraphs.into_iter()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paragraphs() {
+        let input = "1\n2\n\n3\r\n\r\n\r\n4\n  \n5\n\n";
+        assert_eq!(
+            paragraphs(input).collect::<Vec<_>>(),
+            vec!["1\n2", "3", "4", "5"]
+        );
+        assert_eq!(paragraphs("\n\n").count(), 0);
+    }
+
+    #[test]
+    fn test_ints() {
+        assert_eq!(ints::<u32>("move 3 from 1 to 12"), vec![3, 1, 12]);
+        assert_eq!(ints::<i32>("2-4,6-8"), vec![2, 4, 6, 8]);
+        assert_eq!(ints::<i64>("x=-3, y=-12 -5"), vec![-3, -12, -5]);
+        assert_eq!(ints::<u8>("no numbers"), Vec::<u8>::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "\"-3\" in \"x=-3\" is out of range")]
+    fn test_ints_out_of_range() {
+        ints::<u32>("x=-3");
+    }
+
+    #[test]
+    fn test_scan() {
+        assert_eq!(
+            scan("move {} from {} to {}", "move 3 from 1 to 2"),
+            Some(vec!["3", "1", "2"])
+        );
+        assert_eq!(scan("$ cd {}", "$ cd a b"), Some(vec!["a b"]));
+        assert_eq!(scan("{} -> {}", "a -> b -> c"), Some(vec!["a", "b -> c"]));
+        assert_eq!(scan("move {} from {}", "move 3 to 1"), None);
+        assert_eq!(scan("noop", "noop"), Some(vec![]));
+
+        assert_eq!(
+            crate::scan!("Sensor at x=2, y=-18", "Sensor at x={}, y={}", u32, i32),
+            Some((2, -18))
+        );
+        assert_eq!(crate::scan!("x=a", "x={}", u32), None);
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let input = "1 2\n3 4\nfive 6\n";
+        let parse_line = |line| crate::scan!(line, "{} {}", u8, u8);
+
+        assert_eq!(
+            parse_lines("1 2\r\n3 4", parse_line),
+            Ok(vec![(1, 2), (3, 4)])
+        );
+
+        let error = parse_lines(input, parse_line).unwrap_err();
+        assert_eq!(
+            error,
+            ParseError {
+                line: 3,
+                text: "five 6".to_string()
+            }
+        );
+        assert_eq!(error.to_string(), "could not parse line 3: \"five 6\"");
+    }
+}
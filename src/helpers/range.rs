@@ -0,0 +1,272 @@
+use std::{cmp::Ordering, fmt::Display};
+
+/// An inclusive range of integers, e.g. `2-4` contains 2, 3 and 4. Never empty.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    /// # Panics
+    ///
+    /// If `start` is after `end`.
+    pub fn new(start: i64, end: i64) -> Self {
+        assert!(start <= end, "interval {start}-{end} is empty");
+        Interval { start, end }
+    }
+
+    /// Number of integers in the interval.
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> u64 {
+        self.start.abs_diff(self.end) + 1
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    /// Whether `other` lies completely within this interval.
+    pub fn contains_interval(&self, other: &Interval) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        self.overlaps(other).then(|| Interval {
+            start: self.start.max(other.start),
+            end: self.end.min(other.end),
+        })
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+/// A set of integers, stored as sorted intervals that neither overlap nor touch.
+/// Useful when the ranges of a puzzle are too large to store every integer.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RangeSet {
+    intervals: Vec<Interval>,
+}
+
+impl RangeSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        // the intervals in `first..last` overlap or touch the new one and are merged into it.
+        let first = self
+            .intervals
+            .partition_point(|existing| existing.end < interval.start.saturating_sub(1));
+        let last = self
+            .intervals
+            .partition_point(|existing| existing.start <= interval.end.saturating_add(1));
+
+        let merged = self.intervals[first..last]
+            .iter()
+            .fold(interval, |merged, existing| Interval {
+                start: merged.start.min(existing.start),
+                end: merged.end.max(existing.end),
+            });
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    /// Total number of integers in the set.
+    pub fn len(&self) -> u64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.find(value).is_ok()
+    }
+
+    /// Whether every integer of `interval` is in the set.
+    pub fn contains_interval(&self, interval: &Interval) -> bool {
+        self.find(interval.start)
+            .is_ok_and(|idx| self.intervals[idx].contains_interval(interval))
+    }
+
+    /// Whether any integer of `interval` is in the set.
+    pub fn overlaps(&self, interval: &Interval) -> bool {
+        let idx = self
+            .intervals
+            .partition_point(|existing| existing.end < interval.start);
+        self.intervals
+            .get(idx)
+            .is_some_and(|existing| existing.overlaps(interval))
+    }
+
+    pub fn union(&self, other: &RangeSet) -> RangeSet {
+        let mut union = self.clone();
+        for &interval in &other.intervals {
+            union.insert(interval);
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &RangeSet) -> RangeSet {
+        let mut intervals = Vec::new();
+        let (mut left, mut right) = (0, 0);
+
+        while let (Some(a), Some(b)) = (self.intervals.get(left), other.intervals.get(right)) {
+            intervals.extend(a.intersection(b));
+            if a.end < b.end {
+                left += 1;
+            } else {
+                right += 1;
+            }
+        }
+
+        RangeSet { intervals }
+    }
+
+    /// All integers of this set that are not in `other`.
+    pub fn difference(&self, other: &RangeSet) -> RangeSet {
+        let mut intervals = Vec::new();
+
+        for &interval in &self.intervals {
+            let mut rest = Some(interval);
+            for removed in &other.intervals {
+                let Some(current) = rest else { break };
+                if !removed.overlaps(&current) {
+                    continue;
+                }
+                if removed.start > current.start {
+                    intervals.push(Interval::new(current.start, removed.start - 1));
+                }
+                rest = (removed.end < current.end)
+                    .then(|| Interval::new(removed.end + 1, current.end));
+            }
+            intervals.extend(rest);
+        }
+
+        RangeSet { intervals }
+    }
+
+    /// The intervals of the set in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = &Interval> {
+        self.intervals.iter()
+    }
+
+    fn find(&self, value: i64) -> Result<usize, usize> {
+        self.intervals.binary_search_by(|interval| {
+            if interval.end < value {
+                Ordering::Less
+            } else if interval.start > value {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+    }
+}
+
+impl From<Interval> for RangeSet {
+    fn from(interval: Interval) -> Self {
+        RangeSet {
+            intervals: vec![interval],
+        }
+    }
+}
+
+impl FromIterator<Interval> for RangeSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        let mut set = RangeSet::new();
+        for interval in iter {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i64, i64)]) -> RangeSet {
+        intervals
+            .iter()
+            .map(|&(start, end)| Interval::new(start, end))
+            .collect()
+    }
+
+    fn intervals(set: &RangeSet) -> Vec<(i64, i64)> {
+        set.iter()
+            .map(|interval| (interval.start, interval.end))
+            .collect()
+    }
+
+    #[test]
+    fn test_interval() {
+        let interval = Interval::new(2, 6);
+        assert_eq!(interval.len(), 5);
+        assert!(interval.contains(6));
+        assert!(!interval.contains(7));
+        assert!(interval.contains_interval(&Interval::new(3, 6)));
+        assert!(!interval.contains_interval(&Interval::new(1, 3)));
+        assert!(interval.overlaps(&Interval::new(6, 8)));
+        assert!(!interval.overlaps(&Interval::new(7, 8)));
+        assert_eq!(
+            interval.intersection(&Interval::new(4, 9)),
+            Some(Interval::new(4, 6))
+        );
+        assert_eq!(interval.intersection(&Interval::new(-3, 1)), None);
+        assert_eq!(Interval::new(-3, -1).to_string(), "-3--1");
+    }
+
+    #[test]
+    #[should_panic(expected = "interval 3-2 is empty")]
+    fn test_empty_interval() {
+        Interval::new(3, 2);
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut ranges = set(&[(10, 12), (1, 3), (20, 25)]);
+        assert_eq!(intervals(&ranges), vec![(1, 3), (10, 12), (20, 25)]);
+
+        // touching intervals are merged.
+        ranges.insert(Interval::new(4, 5));
+        ranges.insert(Interval::new(11, 21));
+        assert_eq!(intervals(&ranges), vec![(1, 5), (10, 25)]);
+        assert_eq!(ranges.len(), 21);
+
+        assert!(ranges.contains(10));
+        assert!(!ranges.contains(7));
+        assert!(ranges.contains_interval(&Interval::new(12, 20)));
+        assert!(!ranges.contains_interval(&Interval::new(4, 10)));
+        assert!(ranges.overlaps(&Interval::new(4, 10)));
+        assert!(!ranges.overlaps(&Interval::new(6, 9)));
+        assert!(RangeSet::new().is_empty());
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let left = set(&[(1, 5), (10, 20)]);
+        let right = set(&[(3, 12), (15, 15), (30, 31)]);
+
+        assert_eq!(intervals(&left.union(&right)), vec![(1, 20), (30, 31)]);
+        assert_eq!(
+            intervals(&left.intersection(&right)),
+            vec![(3, 5), (10, 12), (15, 15)]
+        );
+        assert_eq!(
+            intervals(&left.difference(&right)),
+            vec![(1, 2), (13, 14), (16, 20)]
+        );
+        assert_eq!(intervals(&right.difference(&left)), vec![(6, 9), (30, 31)]);
+        assert!(left.difference(&left).is_empty());
+    }
+}
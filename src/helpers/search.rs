@@ -0,0 +1,262 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use super::{Grid, Point};
+
+/// A path found by a search.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<N, C> {
+    /// Nodes from the start to the goal, both inclusive.
+    pub nodes: Vec<N>,
+    pub cost: C,
+    /// Number of nodes the search expanded before reaching the goal, useful to compare heuristics.
+    pub visited: usize,
+}
+
+fn reconstruct<N: Clone>(goal: N, parent: impl Fn(&N) -> Option<N>) -> Vec<N> {
+    let mut nodes = vec![goal];
+    while let Some(node) = parent(nodes.last().unwrap()) {
+        nodes.push(node);
+    }
+    nodes.reverse();
+    nodes
+}
+
+/// Breadth-first search for the path with the fewest steps from `start` to a node that satisfies `is_goal`.
+pub fn bfs<N, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut parents: HashMap<N, Option<N>> = HashMap::from([(start.clone(), None)]);
+    let mut queue = VecDeque::from([start]);
+    let mut visited = 0;
+
+    while let Some(node) = queue.pop_front() {
+        visited += 1;
+
+        if is_goal(&node) {
+            let nodes = reconstruct(node, |node| parents[node].clone());
+            return Some(Path {
+                cost: nodes.len() - 1,
+                nodes,
+                visited,
+            });
+        }
+
+        for next in neighbors(&node) {
+            if !parents.contains_key(&next) {
+                parents.insert(next.clone(), Some(node.clone()));
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// Returns every node reachable from `start` with the fewest number of steps needed to reach it.
+pub fn reachable<N, I>(start: N, mut neighbors: impl FnMut(&N) -> I) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        let distance = distances[&node];
+        for next in neighbors(&node) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), distance + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    distances
+}
+
+/// Dijkstra's algorithm. `neighbors` returns the adjacent nodes with the cost to move there.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    neighbors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, neighbors, |_| C::default(), is_goal)
+}
+
+/// A* search. `heuristic` estimates the remaining cost to the goal and must never overestimate it,
+/// e.g. the manhattan distance on a grid.
+pub fn astar<N, C, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    // the queue refers to nodes by index, so nodes don't need to be ordered.
+    // among nodes with the same estimate, the one furthest from the start is expanded first.
+    let mut nodes = vec![start.clone()];
+    let mut best: HashMap<N, (C, Option<N>)> =
+        HashMap::from([(start.clone(), (C::default(), None))]);
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), Reverse(C::default()), 0))]);
+    let mut visited = 0;
+
+    while let Some(Reverse((_, Reverse(cost), idx))) = queue.pop() {
+        let node = nodes[idx].clone();
+        if best[&node].0 < cost {
+            continue;
+        }
+        visited += 1;
+
+        if is_goal(&node) {
+            return Some(Path {
+                nodes: reconstruct(node, |node| best[node].1.clone()),
+                cost,
+                visited,
+            });
+        }
+
+        for (next, step_cost) in neighbors(&node) {
+            let next_cost = cost + step_cost;
+            if best
+                .get(&next)
+                .is_some_and(|&(best_cost, _)| best_cost <= next_cost)
+            {
+                continue;
+            }
+
+            best.insert(next.clone(), (next_cost, Some(node.clone())));
+            queue.push(Reverse((
+                next_cost + heuristic(&next),
+                Reverse(next_cost),
+                nodes.len(),
+            )));
+            nodes.push(next);
+        }
+    }
+
+    None
+}
+
+impl<T> Grid<T> {
+    /// Shortest path between orthogonally adjacent cells from `start` to `goal`.
+    /// Moving from one cell to the next is only possible if `can_move(from, to)` holds.
+    pub fn shortest_path(
+        &self,
+        start: Point,
+        goal: Point,
+        mut can_move: impl FnMut(&T, &T) -> bool,
+    ) -> Option<Path<Point, usize>> {
+        astar(
+            start,
+            |&point| {
+                self.neighbors4(point)
+                    .filter(|&next| can_move(&self[point], &self[next]))
+                    .map(|next| (next, 1))
+                    .collect::<Vec<_>>()
+            },
+            |point| point.manhattan_distance(goal),
+            |&point| point == goal,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAZE: &str = "S.#.\n..#.\n.#..\n...E";
+
+    fn maze() -> (Grid<char>, Point, Point) {
+        let grid = Grid::parse(MAZE).unwrap();
+        let start = grid.position(|&ch| ch == 'S').unwrap();
+        let goal = grid.position(|&ch| ch == 'E').unwrap();
+        (grid, start, goal)
+    }
+
+    #[test]
+    fn test_bfs() {
+        let (grid, start, goal) = maze();
+        let path = bfs(
+            start,
+            |&point| grid.neighbors4(point).filter(|&next| grid[next] != '#'),
+            |&point| point == goal,
+        )
+        .unwrap();
+
+        assert_eq!(path.cost, 6);
+        assert_eq!(path.nodes.first(), Some(&start));
+        assert_eq!(path.nodes.last(), Some(&goal));
+        assert!(path.visited <= 12);
+
+        assert_eq!(bfs(start, |_| [], |&point| point == goal), None);
+    }
+
+    #[test]
+    fn test_reachable() {
+        let (grid, start, _) = maze();
+        let distances = reachable(start, |&point| {
+            grid.neighbors4(point).filter(|&next| grid[next] != '#')
+        });
+
+        assert_eq!(distances.len(), 13);
+        assert_eq!(distances[&Point::new(3, 0)], 9);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // the direct edge from a to c is more expensive than the detour via b.
+        let edges = HashMap::from([
+            ('a', vec![('b', 1), ('c', 5)]),
+            ('b', vec![('c', 2)]),
+            ('c', vec![]),
+        ]);
+        let path = dijkstra('a', |node| edges[node].clone(), |&node| node == 'c').unwrap();
+
+        assert_eq!(path.nodes, vec!['a', 'b', 'c']);
+        assert_eq!(path.cost, 3);
+        assert_eq!(
+            dijkstra('c', |node| edges[node].clone(), |&node| node == 'a'),
+            None
+        );
+    }
+
+    #[test]
+    fn test_astar() {
+        let (grid, start, goal) = maze();
+        let path = grid.shortest_path(start, goal, |_, &to| to != '#').unwrap();
+        assert_eq!(path.cost, 6);
+
+        let open = Grid::filled(20, 20, '.');
+        let (start, goal) = (Point::new(0, 0), Point::new(19, 19));
+        let astar_path = open.shortest_path(start, goal, |_, _| true).unwrap();
+        let dijkstra_path = dijkstra(
+            start,
+            |&point| open.neighbors4(point).map(|next| (next, 1)),
+            |&point| point == goal,
+        )
+        .unwrap();
+
+        assert_eq!(astar_path.cost, dijkstra_path.cost);
+        assert!(astar_path.visited < dijkstra_path.visited);
+    }
+}
@@ -0,0 +1,209 @@
+use std::{
+    collections::HashMap,
+    ops::{Index, IndexMut},
+};
+
+/// Refers to a node of a [`Tree`]. Only valid for the tree that created it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(usize);
+
+struct Node<T> {
+    name: String,
+    value: T,
+    parent: Option<NodeId>,
+    children: Vec<NodeId>,
+    children_by_name: HashMap<String, NodeId>,
+}
+
+/// A tree whose nodes live in one `Vec` and refer to each other by [`NodeId`].
+/// Every node except the root has a name that is unique among its siblings, e.g. a file system.
+pub struct Tree<T> {
+    nodes: Vec<Node<T>>,
+}
+
+impl<T> Tree<T> {
+    pub fn new(root: T) -> Self {
+        Tree {
+            nodes: vec![Node {
+                name: String::new(),
+                value: root,
+                parent: None,
+                children: Vec::new(),
+                children_by_name: HashMap::new(),
+            }],
+        }
+    }
+
+    pub fn root(&self) -> NodeId {
+        NodeId(0)
+    }
+
+    /// Number of nodes, including the root.
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Adds a child named `name` to `parent`.
+    /// If `parent` already has a child with that name, its value is replaced and its id returned.
+    pub fn add_child(&mut self, parent: NodeId, name: &str, value: T) -> NodeId {
+        if let Some(child) = self.child(parent, name) {
+            self[child] = value;
+            return child;
+        }
+
+        let child = NodeId(self.nodes.len());
+        self.nodes.push(Node {
+            name: name.to_string(),
+            value,
+            parent: Some(parent),
+            children: Vec::new(),
+            children_by_name: HashMap::new(),
+        });
+
+        let parent = &mut self.nodes[parent.0];
+        parent.children.push(child);
+        parent.children_by_name.insert(name.to_string(), child);
+        child
+    }
+
+    /// The name of the node, empty for the root.
+    pub fn name(&self, id: NodeId) -> &str {
+        &self.nodes[id.0].name
+    }
+
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.nodes[id.0].parent
+    }
+
+    pub fn child(&self, id: NodeId, name: &str) -> Option<NodeId> {
+        self.nodes[id.0].children_by_name.get(name).copied()
+    }
+
+    /// The children of the node in the order they were added.
+    pub fn children(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.nodes[id.0].children.iter().copied()
+    }
+
+    /// The node and all of its descendants, every node before its children.
+    pub fn pre_order(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        let mut stack = vec![id];
+        std::iter::from_fn(move || {
+            let id = stack.pop()?;
+            stack.extend(self.nodes[id.0].children.iter().rev());
+            Some(id)
+        })
+    }
+
+    /// The node and all of its descendants, every node after its children.
+    pub fn post_order(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        // a reversed pre-order that visits the children from last to first.
+        let mut stack = vec![id];
+        let mut order = Vec::new();
+        while let Some(id) = stack.pop() {
+            order.push(id);
+            stack.extend(self.nodes[id.0].children.iter());
+        }
+        order.into_iter().rev()
+    }
+
+    /// Combines the values of the subtree of `id` bottom-up: `f` receives the value of a node
+    /// and the results of its children. Returns the result of every node in the subtree.
+    pub fn fold<A>(&self, id: NodeId, mut f: impl FnMut(&T, &[&A]) -> A) -> HashMap<NodeId, A> {
+        let mut results = HashMap::new();
+        for id in self.post_order(id) {
+            let children: Vec<&A> = self.children(id).map(|child| &results[&child]).collect();
+            let result = f(&self[id], &children);
+            results.insert(id, result);
+        }
+        results
+    }
+}
+
+impl<T> Index<NodeId> for Tree<T> {
+    type Output = T;
+
+    fn index(&self, id: NodeId) -> &T {
+        &self.nodes[id.0].value
+    }
+}
+
+impl<T> IndexMut<NodeId> for Tree<T> {
+    fn index_mut(&mut self, id: NodeId) -> &mut T {
+        &mut self.nodes[id.0].value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `/` with `a` (containing `c` and `d`) and `b`.
+    fn tree() -> Tree<u32> {
+        let mut tree = Tree::new(1);
+        let a = tree.add_child(tree.root(), "a", 2);
+        tree.add_child(tree.root(), "b", 3);
+        tree.add_child(a, "c", 4);
+        tree.add_child(a, "d", 5);
+        tree
+    }
+
+    fn names(tree: &Tree<u32>, ids: impl Iterator<Item = NodeId>) -> Vec<&str> {
+        ids.map(|id| tree.name(id)).collect()
+    }
+
+    #[test]
+    fn test_navigation() {
+        let mut tree = tree();
+        let a = tree.child(tree.root(), "a").unwrap();
+        let c = tree.child(a, "c").unwrap();
+
+        assert_eq!(tree.len(), 5);
+        assert_eq!(tree[c], 4);
+        assert_eq!(tree.parent(c), Some(a));
+        assert_eq!(tree.parent(tree.root()), None);
+        assert_eq!(tree.child(a, "b"), None);
+        assert_eq!(names(&tree, tree.children(a)), vec!["c", "d"]);
+
+        // adding an existing name replaces the value.
+        assert_eq!(tree.add_child(a, "c", 6), c);
+        tree[c] += 1;
+        assert_eq!(tree[c], 7);
+        assert_eq!(tree.len(), 5);
+    }
+
+    #[test]
+    fn test_traversal() {
+        let tree = tree();
+        let a = tree.child(tree.root(), "a").unwrap();
+
+        assert_eq!(
+            names(&tree, tree.pre_order(tree.root())),
+            vec!["", "a", "c", "d", "b"]
+        );
+        assert_eq!(
+            names(&tree, tree.post_order(tree.root())),
+            vec!["c", "d", "a", "b", ""]
+        );
+        assert_eq!(names(&tree, tree.post_order(a)), vec!["c", "d", "a"]);
+    }
+
+    #[test]
+    fn test_fold() {
+        let tree = tree();
+        let a = tree.child(tree.root(), "a").unwrap();
+
+        let sums = tree.fold(tree.root(), |value, children| {
+            value + children.iter().copied().sum::<u32>()
+        });
+        assert_eq!(sums.len(), 5);
+        assert_eq!(sums[&tree.root()], 15);
+        assert_eq!(sums[&a], 11);
+
+        let depths = tree.fold(a, |_, children| {
+            1 + children.iter().copied().max().copied().unwrap_or(0)
+        });
+        assert_eq!(depths.len(), 3);
+        assert_eq!(depths[&a], 2);
+    }
+}
@@ -0,0 +1,311 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs::{self, create_dir_all},
+};
+
+use serde::Deserialize;
+
+use crate::aoc_client::{self, AocClientError, Client};
+
+/// Environment variable that holds the id of the private leaderboard.
+pub const ID_ENV_VAR: &str = "AOC_LEADERBOARD_ID";
+/// Folder that holds downloaded leaderboards. Not checked into git, it contains other people's data.
+pub const CACHE_FOLDER: &str = ".cache";
+/// The site asks to not fetch a private leaderboard more often than every 15 minutes.
+pub const MIN_REFRESH_SECS: i64 = 15 * 60;
+
+pub enum LeaderboardError {
+    MissingId,
+    NotCached,
+    BadFormat,
+    IoError,
+    ClientError(AocClientError),
+}
+
+impl Display for LeaderboardError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LeaderboardError::MissingId => write!(
+                f,
+                "no leaderboard id given. Pass --id or set the {ID_ENV_VAR} environment variable."
+            ),
+            LeaderboardError::NotCached => write!(f, "leaderboard is not cached yet."),
+            LeaderboardError::BadFormat => write!(f, "leaderboard data could not be parsed."),
+            LeaderboardError::IoError => write!(f, "could not read or write leaderboard cache."),
+            LeaderboardError::ClientError(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<AocClientError> for LeaderboardError {
+    fn from(e: AocClientError) -> Self {
+        LeaderboardError::ClientError(e)
+    }
+}
+
+#[derive(Deserialize)]
+pub struct Star {
+    pub get_star_ts: i64,
+}
+
+#[derive(Deserialize)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u32,
+    pub last_star_ts: i64,
+    /// Stars by day and part, both keyed by their number as a string.
+    pub completion_day_level: HashMap<String, HashMap<String, Star>>,
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    /// Unix timestamp at which the star for `part` of `day` was earned.
+    pub fn star_ts(&self, day: u8, part: u8) -> Option<i64> {
+        self.completion_day_level
+            .get(&day.to_string())?
+            .get(&part.to_string())
+            .map(|star| star.get_star_ts)
+    }
+}
+
+#[derive(Deserialize)]
+pub struct Leaderboard {
+    pub event: String,
+    pub members: HashMap<String, Member>,
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> Result<Self, LeaderboardError> {
+        serde_json::from_str(json).map_err(|_| LeaderboardError::BadFormat)
+    }
+
+    pub fn year(&self) -> u16 {
+        self.event.parse().unwrap_or_default()
+    }
+
+    /// Members ordered like on the site: by local score, then by who got there first.
+    pub fn standings(&self) -> Vec<&Member> {
+        let mut members: Vec<_> = self.members.values().collect();
+        members.sort_by(|a, b| {
+            b.local_score
+                .cmp(&a.local_score)
+                .then(b.stars.cmp(&a.stars))
+                .then(a.last_star_ts.cmp(&b.last_star_ts))
+                .then(a.id.cmp(&b.id))
+        });
+        members
+    }
+}
+
+pub fn cache_path(id: u64, year: u16) -> String {
+    format!("{CACHE_FOLDER}/leaderboard-{year}-{id}.json")
+}
+
+/// Loads a leaderboard, fetching it if the cached copy is missing or stale.
+/// With `offline` set, only the cached copy is used.
+pub fn load(id: u64, year: u16, offline: bool) -> Result<Leaderboard, LeaderboardError> {
+    let path = cache_path(id, year);
+
+    let cache_age = fs::metadata(&path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| modified.elapsed().ok())
+        .map(|elapsed| elapsed.as_secs() as i64);
+
+    let is_fresh = cache_age.is_some_and(|age| age < MIN_REFRESH_SECS);
+
+    if offline || is_fresh {
+        let json = fs::read_to_string(&path).map_err(|_| LeaderboardError::NotCached)?;
+        return Leaderboard::parse(&json);
+    }
+
+    let json = Client::from_env()?.get_leaderboard(id, year)?;
+    let leaderboard = Leaderboard::parse(&json)?;
+
+    create_dir_all(CACHE_FOLDER).map_err(|_| LeaderboardError::IoError)?;
+    fs::write(&path, json).map_err(|_| LeaderboardError::IoError)?;
+
+    Ok(leaderboard)
+}
+
+/// Formats a duration in seconds as `HH:MM:SS`. Hours can exceed 24.
+fn format_duration(secs: i64) -> String {
+    format!(
+        "{:02}:{:02}:{:02}",
+        secs / 3600,
+        secs % 3600 / 60,
+        secs % 60
+    )
+}
+
+/// Renders the overall standings with a star for every completed day.
+/// `★` marks both parts solved, `☆` only part one.
+pub fn render_standings(leaderboard: &Leaderboard) -> String {
+    let year = leaderboard.year();
+    let days = aoc_client::event_days(year);
+    let mut out = format!("Leaderboard {}\n\n", leaderboard.event);
+
+    let days_header: String = (1..=days).map(|day| (day % 10).to_string()).collect();
+    out.push_str(&format!(
+        "{:>4} {:>5} {:>5}  {days_header}\n",
+        "", "score", "stars"
+    ));
+
+    for (rank, member) in leaderboard.standings().into_iter().enumerate() {
+        let stars: String = (1..=days)
+            .map(
+                |day| match (member.star_ts(day, 1), member.star_ts(day, 2)) {
+                    (Some(_), Some(_)) => '★',
+                    (Some(_), None) => '☆',
+                    _ => '·',
+                },
+            )
+            .collect();
+
+        out.push_str(&format!(
+            "{:>3}) {:>5} {:>5}  {stars}  {}\n",
+            rank + 1,
+            member.local_score,
+            member.stars,
+            member.display_name()
+        ));
+    }
+
+    out
+}
+
+/// Renders the times members needed for each part of a day, counted from the unlock,
+/// and the time between part one and part two.
+pub fn render_day(leaderboard: &Leaderboard, day: u8) -> String {
+    let unlock_time = aoc_client::unlock_time(day, leaderboard.year());
+    let mut out = format!("Leaderboard {}, day {day}\n\n", leaderboard.event);
+    out.push_str(&format!(
+        "{:>4} {:>9} {:>9} {:>9}\n",
+        "", "part 1", "part 2", "delta"
+    ));
+
+    let mut members: Vec<_> = leaderboard
+        .members
+        .values()
+        .filter_map(|member| {
+            let part_one = member.star_ts(day, 1)?;
+            Some((member, part_one, member.star_ts(day, 2)))
+        })
+        .collect();
+
+    // solvers of both parts first, ordered by when they finished.
+    members.sort_by_key(|(member, part_one, part_two)| {
+        (part_two.is_none(), part_two.unwrap_or(*part_one), member.id)
+    });
+
+    for (rank, (member, part_one, part_two)) in members.into_iter().enumerate() {
+        let format_optional = |secs: Option<i64>| secs.map_or("-".to_string(), format_duration);
+
+        out.push_str(&format!(
+            "{:>3}) {:>9} {:>9} {:>9}  {}\n",
+            rank + 1,
+            format_duration(part_one - unlock_time),
+            format_optional(part_two.map(|ts| ts - unlock_time)),
+            format_optional(part_two.map(|ts| ts - part_one)),
+            member.display_name()
+        ));
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // day 1 of 2022 unlocked at 1669870800.
+    const FIXTURE: &str = r#"{
+        "owner_id": 1,
+        "event": "2022",
+        "members": {
+            "1": {
+                "id": 1, "name": "Alice", "stars": 3, "local_score": 10, "global_score": 0,
+                "last_star_ts": 1669960000,
+                "completion_day_level": {
+                    "1": {
+                        "1": {"get_star_ts": 1669871100, "star_index": 1},
+                        "2": {"get_star_ts": 1669871400, "star_index": 2}
+                    },
+                    "2": {"1": {"get_star_ts": 1669960000, "star_index": 3}}
+                }
+            },
+            "2": {
+                "id": 2, "name": null, "stars": 2, "local_score": 10, "global_score": 0,
+                "last_star_ts": 1669872000,
+                "completion_day_level": {
+                    "1": {
+                        "1": {"get_star_ts": 1669870900, "star_index": 1},
+                        "2": {"get_star_ts": 1669872000, "star_index": 2}
+                    }
+                }
+            },
+            "3": {
+                "id": 3, "name": "Carol", "stars": 0, "local_score": 0, "global_score": 0,
+                "last_star_ts": 0, "completion_day_level": {}
+            }
+        }
+    }"#;
+
+    #[test]
+    fn test_render_standings() {
+        let leaderboard = Leaderboard::parse(FIXTURE).ok().unwrap();
+        let dots = "·".repeat(23);
+
+        assert_eq!(
+            render_standings(&leaderboard),
+            format!(
+                "Leaderboard 2022\n\n\
+                \x20    score stars  1234567890123456789012345\n\
+                \x20 1)    10     3  ★☆{dots}  Alice\n\
+                \x20 2)    10     2  ★·{dots}  (anonymous user #2)\n\
+                \x20 3)     0     0  ··{dots}  Carol\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_render_day() {
+        let leaderboard = Leaderboard::parse(FIXTURE).ok().unwrap();
+
+        assert_eq!(
+            render_day(&leaderboard, 1),
+            "Leaderboard 2022, day 1\n\n\
+            \x20       part 1    part 2     delta\n\
+            \x20 1)  00:05:00  00:10:00  00:05:00  Alice\n\
+            \x20 2)  00:01:40  00:20:00  00:18:20  (anonymous user #2)\n"
+        );
+
+        assert_eq!(
+            render_day(&leaderboard, 2),
+            "Leaderboard 2022, day 2\n\n\
+            \x20       part 1    part 2     delta\n\
+            \x20 1)  00:46:40         -         -  Alice\n"
+        );
+    }
+
+    #[test]
+    fn test_bad_format() {
+        assert!(matches!(
+            Leaderboard::parse("{}"),
+            Err(LeaderboardError::BadFormat)
+        ));
+    }
+}
@@ -4,9 +4,23 @@
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
 use std::env;
+use std::fmt::Display;
 use std::fs;
 
+pub mod aoc_client;
+pub mod crypt;
 pub mod helpers;
+pub mod leaderboard;
+pub mod markdown;
+pub mod puzzle;
+pub mod stars;
+pub mod template;
+pub mod vscode;
+
+#[cfg(feature = "embed-inputs")]
+mod embedded {
+    include!(concat!(env!("OUT_DIR"), "/inputs.rs"));
+}
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
@@ -41,13 +55,251 @@ macro_rules! solve {
     }};
 }
 
+/// Controls how puzzle files are cleaned up after they are read from disk.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Normalize {
+    /// Convert `\r\n` (and stray `\r`) line endings to `\n`.
+    pub line_endings: bool,
+    /// Strip trailing whitespace from every line.
+    /// Off by default, as some puzzles (e.g. day 05) rely on column positions.
+    pub trailing_whitespace: bool,
+    /// Strip trailing newlines and blank lines at the end of the file.
+    pub trailing_newlines: bool,
+}
+
+impl Normalize {
+    /// Leave the file contents untouched.
+    pub const NONE: Self = Self {
+        line_endings: false,
+        trailing_whitespace: false,
+        trailing_newlines: false,
+    };
+
+    pub fn apply(&self, input: &str) -> String {
+        let mut output = if self.line_endings {
+            input.replace("\r\n", "\n").replace('\r', "\n")
+        } else {
+            input.to_string()
+        };
+
+        if self.trailing_whitespace {
+            output = output
+                .split('\n')
+                .map(|line| line.trim_end_matches([' ', '\t']))
+                .collect::<Vec<_>>()
+                .join("\n");
+        }
+
+        if self.trailing_newlines {
+            let mut lines: Vec<_> = output.split('\n').collect();
+            while lines.last().is_some_and(|line| line.trim().is_empty()) {
+                lines.pop();
+            }
+            output = lines.join("\n");
+        }
+
+        output
+    }
+}
+
+impl Default for Normalize {
+    fn default() -> Self {
+        Self {
+            line_endings: true,
+            trailing_whitespace: false,
+            trailing_newlines: true,
+        }
+    }
+}
+
 pub fn read_file(folder: &str, day: u8) -> String {
+    read_file_with(folder, day, Normalize::default())
+}
+
+pub fn read_file_with(folder: &str, day: u8, normalize: Normalize) -> String {
+    #[cfg(feature = "embed-inputs")]
+    if folder == "inputs" {
+        let input = usize::from(day)
+            .checked_sub(1)
+            .and_then(|idx| embedded::INPUTS.get(idx).copied().flatten())
+            .expect("input file was not present at compile time");
+        return normalize.apply(input);
+    }
+
+    let cwd = env::current_dir().unwrap();
+
+    let mut dir = cwd.join("src").join(folder);
+    // named accounts keep their inputs in a subfolder.
+    if folder == "inputs" {
+        if let Some(account) = aoc_client::account().unwrap_or_else(|e| panic!("{e}")) {
+            dir = dir.join(account);
+        }
+    }
+
+    let filepath = dir.join(format!("{day:02}.txt"));
+
+    // fall back to an encrypted copy of the file if the plain one is not present.
+    let f = fs::read_to_string(&filepath).or_else(|e| {
+        if !crypt::encrypted_path(&filepath).is_file() {
+            return Err(e);
+        }
+        Ok(crypt::read_encrypted(&filepath)
+            .unwrap_or_else(|e| panic!("could not decrypt input file: {e}")))
+    });
+    let contents = normalize.apply(&f.expect("could not open input file"));
+
+    if folder == "examples" {
+        Example::parse("", &contents).input
+    } else {
+        contents
+    }
+}
+
+/// An example input, optionally annotated with the expected answers in a header:
+///
+/// ```text
+/// part_one: 7
+/// part_two: 19
+/// ---
+/// mjqjpqmgbljsphdztnvjfqwrcgsmlb
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+impl Example {
+    pub const HEADER_SEPARATOR: &'static str = "---";
+
+    pub fn parse(name: &str, contents: &str) -> Self {
+        let mut example = Self {
+            name: name.to_string(),
+            input: contents.to_string(),
+            part_one: None,
+            part_two: None,
+        };
+
+        let Some((header, input)) = contents.split_once(&format!("\n{}\n", Self::HEADER_SEPARATOR))
+        else {
+            return example;
+        };
+
+        let mut part_one = None;
+        let mut part_two = None;
+
+        for line in header.lines() {
+            match line.split_once(':') {
+                Some(("part_one", answer)) => part_one = Some(answer.trim().to_string()),
+                Some(("part_two", answer)) => part_two = Some(answer.trim().to_string()),
+                // not a header after all, treat the whole file as input.
+                _ => return example,
+            }
+        }
+
+        example.input = input.to_string();
+        example.part_one = part_one.filter(|answer| !answer.is_empty());
+        example.part_two = part_two.filter(|answer| !answer.is_empty());
+        example
+    }
+
+    pub fn answer(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_one.as_deref(),
+            2 => self.part_two.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// Formats the example as it is stored on disk, including the header if answers are known.
+impl Display for Example {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.part_one.is_some() || self.part_two.is_some() {
+            writeln!(
+                f,
+                "part_one: {}",
+                self.part_one.as_deref().unwrap_or_default()
+            )?;
+            writeln!(
+                f,
+                "part_two: {}",
+                self.part_two.as_deref().unwrap_or_default()
+            )?;
+            writeln!(f, "{}", Self::HEADER_SEPARATOR)?;
+        }
+        write!(f, "{}", self.input)
+    }
+}
+
+/// Reads all examples for a day: `NN.txt` as well as named examples `NN-<name>.txt`.
+pub fn read_examples(day: u8) -> Vec<Example> {
     let cwd = env::current_dir().unwrap();
+    let folder = cwd.join("src").join("examples");
+    let day_padded = format!("{day:02}");
+
+    let mut examples: Vec<_> = fs::read_dir(folder)
+        .expect("could not open examples folder")
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let name = path
+                .file_name()?
+                .to_str()?
+                .strip_suffix(".txt")?
+                .to_string();
+            let is_example = name == day_padded
+                || name
+                    .strip_prefix(&day_padded)
+                    .is_some_and(|suffix| suffix.starts_with('-'));
+
+            if !is_example {
+                return None;
+            }
 
-    let filepath = cwd.join("src").join(folder).join(format!("{day:02}.txt"));
+            let contents = fs::read_to_string(&path).expect("could not open example file");
+            Some(Example::parse(
+                &name,
+                &Normalize::default().apply(&contents),
+            ))
+        })
+        .collect();
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    examples.sort_by(|a, b| a.name.cmp(&b.name));
+    examples
+}
+
+/// Runs `solver` against every example of `day` that has an expected answer for `part`.
+pub fn check_examples<T: Display>(day: u8, part: u8, solver: impl Fn(&str) -> Option<T>) {
+    for example in read_examples(day) {
+        if let Some(expected) = example.answer(part) {
+            let result = solver(&example.input).map(|result| result.to_string());
+            assert_eq!(
+                result.as_deref(),
+                Some(expected),
+                "example \"{}\" part {}",
+                example.name,
+                part
+            );
+        }
+    }
+}
+
+/// Generates tests that check every example file of a day against its expected answers.
+#[macro_export]
+macro_rules! test_examples {
+    ($day:expr, $part_one:ident, $part_two:ident) => {
+        #[test]
+        fn test_examples_part_one() {
+            advent_of_code::check_examples($day, 1, $part_one);
+        }
+
+        #[test]
+        fn test_examples_part_two() {
+            advent_of_code::check_examples($day, 2, $part_two);
+        }
+    };
 }
 
 fn parse_time(val: &str, postfix: &str) -> f64 {
@@ -122,113 +374,50 @@ mod tests {
             10400.50_f64
         );
     }
-}
 
-pub mod aoc_cli {
-    use std::{
-        fmt::Display,
-        fs::create_dir_all,
-        process::{Command, Output, Stdio},
-    };
-
-    pub enum AocCliError {
-        CommandNotFound,
-        CommandNotCallable,
-        BadExitStatus(Output),
-        IoError,
-    }
-
-    impl Display for AocCliError {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            match self {
-                AocCliError::CommandNotFound => write!(f, "aoc-cli is not present in environment."),
-                AocCliError::CommandNotCallable => write!(f, "aoc-cli could not be called."),
-                AocCliError::BadExitStatus(_) => {
-                    write!(f, "aoc-cli exited with a non-zero status.")
-                }
-                AocCliError::IoError => write!(f, "could not write output files to file system."),
-            }
-        }
-    }
-
-    pub fn check() -> Result<(), AocCliError> {
-        Command::new("aoc")
-            .arg("-V")
-            .output()
-            .map_err(|_| AocCliError::CommandNotFound)?;
-        Ok(())
-    }
-
-    pub fn read(day: u8, year: Option<u16>) -> Result<Output, AocCliError> {
-        // TODO: output local puzzle if present.
-        let args = build_args("read", &[], day, year);
-        call_aoc_cli(&args)
-    }
-
-    pub fn download(day: u8, year: Option<u16>) -> Result<Output, AocCliError> {
-        let input_path = get_input_path(day);
-
-        let puzzle_path = get_puzzle_path(day);
-        create_dir_all("src/puzzles").map_err(|_| AocCliError::IoError)?;
-
-        let args = build_args(
-            "download",
-            &[
-                "--overwrite".into(),
-                "--input-file".into(),
-                input_path.to_string(),
-                "--puzzle-file".into(),
-                puzzle_path.to_string(),
-            ],
-            day,
-            year,
+    #[test]
+    fn test_example_parse() {
+        let example = Example::parse("06-1", "part_one: 7\npart_two: 19\n---\nmjqjpqm\n---\n");
+        assert_eq!(example.input, "mjqjpqm\n---\n");
+        assert_eq!(example.answer(1), Some("7"));
+        assert_eq!(example.answer(2), Some("19"));
+
+        let example = Example::parse("05", "part_one: CMZ\npart_two:\n---\n    [D]");
+        assert_eq!(example.input, "    [D]");
+        assert_eq!(example.answer(1), Some("CMZ"));
+        assert_eq!(example.answer(2), None);
+
+        let example = Example::parse("06-1", "part_one: 7\npart_two:\n---\nmjqjpqm\n");
+        assert_eq!(Example::parse("06-1", &example.to_string()), example);
+        assert_eq!(
+            example.to_string(),
+            "part_one: 7\npart_two: \n---\nmjqjpqm\n"
         );
 
-        let output = call_aoc_cli(&args)?;
-
-        if output.status.success() {
-            println!("---");
-            println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-            println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
-            Ok(output)
-        } else {
-            Err(AocCliError::BadExitStatus(output))
-        }
-    }
-
-    fn get_input_path(day: u8) -> String {
-        let day_padded = format!("{day:02}");
-        format!("src/inputs/{day_padded}.txt")
+        let example = Example::parse("01", "1000\n---\n2000");
+        assert_eq!(example.input, "1000\n---\n2000");
+        assert_eq!(example.answer(1), None);
     }
 
-    fn get_puzzle_path(day: u8) -> String {
-        let day_padded = format!("{day:02}");
-        format!("src/puzzles/{day_padded}.md")
-    }
-
-    fn build_args(command: &str, args: &[String], day: u8, year: Option<u16>) -> Vec<String> {
-        let mut cmd_args = args.to_vec();
-
-        if let Some(year) = year {
-            cmd_args.push("--year".into());
-            cmd_args.push(year.to_string());
-        }
-
-        cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
-
-        cmd_args
+    #[test]
+    fn test_normalize_default() {
+        let normalize = Normalize::default();
+        assert_eq!(normalize.apply("1\r\n2\r\n\r\n3\r\n\r\n\r\n"), "1\n2\n\n3");
+        assert_eq!(
+            normalize.apply("    [D]    \n 1 \n\n  \n"),
+            "    [D]    \n 1 "
+        );
+        assert_eq!(normalize.apply(""), "");
     }
 
-    fn call_aoc_cli(args: &[String]) -> Result<Output, AocCliError> {
-        if cfg!(debug_assertions) {
-            println!("Calling >aoc with: {}", args.join(" "));
-        }
-
-        Command::new("aoc")
-            .args(args)
-            .stdout(Stdio::inherit())
-            .stderr(Stdio::inherit())
-            .output()
-            .map_err(|_| AocCliError::CommandNotCallable)
+    #[test]
+    fn test_normalize_options() {
+        let normalize = Normalize {
+            trailing_whitespace: true,
+            ..Normalize::default()
+        };
+        assert_eq!(normalize.apply("a \r\nb\t\r\n"), "a\nb");
+
+        assert_eq!(Normalize::NONE.apply("a\r\n\r\n"), "a\r\n\r\n");
     }
 }
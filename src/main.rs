@@ -14,6 +14,9 @@ fn main() {
             if cfg!(not(debug_assertions)) {
                 args.push("--release");
             }
+            if cfg!(feature = "embed-inputs") {
+                args.push("--features=embed-inputs");
+            }
 
             let cmd = Command::new("cargo").args(&args).output().unwrap();
 
@@ -0,0 +1,191 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+const CODE_INDENT: &str = "    ";
+
+/// Renders the markdown of a puzzle description for display in a terminal.
+/// Paragraphs and list items are wrapped to `width` columns, code blocks are left untouched.
+pub fn render(markdown: &str, width: usize) -> String {
+    let mut out = String::new();
+    let mut lines = markdown.lines().peekable();
+
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim();
+
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        if trimmed.starts_with("```") {
+            for line in lines.by_ref() {
+                if line.trim_start().starts_with("```") {
+                    break;
+                }
+                out.push_str(CODE_INDENT);
+                out.push_str(line);
+                out.push('\n');
+            }
+        } else if let Some(heading) = trimmed.strip_prefix('#') {
+            let heading = heading.trim_start_matches('#').trim();
+            out.push_str(&format!("{ANSI_BOLD}{heading}{ANSI_RESET}\n"));
+        } else if let Some(item) = trimmed.strip_prefix("- ").or(trimmed.strip_prefix("* ")) {
+            let mut item = item.to_string();
+            // list items end at the next item or at a blank line.
+            while let Some(next) = lines.next_if(|next| is_continuation(next)) {
+                item.push(' ');
+                item.push_str(next.trim());
+            }
+            out.push_str(&wrap(&item, width, "- ", "  "));
+            if lines.peek().is_some_and(|next| is_list_item(next)) {
+                continue;
+            }
+        } else {
+            let mut paragraph = trimmed.to_string();
+            while let Some(next) = lines.next_if(|next| is_continuation(next)) {
+                paragraph.push(' ');
+                paragraph.push_str(next.trim());
+            }
+            out.push_str(&wrap(&paragraph, width, "", ""));
+        }
+
+        out.push('\n');
+    }
+
+    let trimmed_len = out.trim_end().len();
+    out.truncate(trimmed_len);
+    out.push('\n');
+    out
+}
+
+fn is_list_item(line: &str) -> bool {
+    let line = line.trim_start();
+    line.starts_with("- ") || line.starts_with("* ")
+}
+
+fn is_continuation(line: &str) -> bool {
+    let trimmed = line.trim();
+    !(trimmed.is_empty()
+        || trimmed.starts_with('#')
+        || trimmed.starts_with("```")
+        || is_list_item(line))
+}
+
+/// A word of inline markdown, with its printable width and its styled representation.
+struct Word {
+    width: usize,
+    styled: String,
+}
+
+fn words(text: &str) -> Vec<Word> {
+    lazy_static! {
+        static ref LINK_PTRN: Regex = Regex::new(r"\[([^\]]*)\]\([^)]*\)").unwrap();
+    }
+
+    let text = LINK_PTRN.replace_all(text, "$1");
+
+    let mut words = Vec::new();
+    let mut word = Word {
+        width: 0,
+        styled: String::new(),
+    };
+    let mut in_code = false;
+    let mut in_emphasis = false;
+
+    for ch in text.chars() {
+        match ch {
+            '`' => in_code = !in_code,
+            '*' if !in_code => {
+                in_emphasis = !in_emphasis;
+                word.styled
+                    .push_str(if in_emphasis { ANSI_ITALIC } else { ANSI_RESET });
+            }
+            ' ' if !in_code => {
+                if word.width > 0 {
+                    words.push(word);
+                    word = Word {
+                        width: 0,
+                        styled: String::new(),
+                    };
+                }
+            }
+            _ => {
+                word.width += 1;
+                word.styled.push(ch);
+            }
+        }
+    }
+
+    if word.width > 0 || !word.styled.is_empty() {
+        words.push(word);
+    }
+
+    words
+}
+
+fn wrap(text: &str, width: usize, first_indent: &str, indent: &str) -> String {
+    let mut out = String::from(first_indent);
+    let mut line_width = first_indent.chars().count();
+    let mut line_empty = true;
+
+    for word in words(text) {
+        if !line_empty && line_width + 1 + word.width > width {
+            out.push('\n');
+            out.push_str(indent);
+            line_width = indent.chars().count();
+            line_empty = true;
+        }
+
+        if !line_empty {
+            out.push(' ');
+            line_width += 1;
+        }
+
+        out.push_str(&word.styled);
+        line_width += word.width;
+        line_empty = false;
+    }
+
+    out.push('\n');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let markdown = "## --- Day 1: Calorie Counting ---\n\n\
+            The Elves take turns writing down the number of *Calories*, see [about](/2022/about).\n\n\
+            ```\n1000\n  2000\n```\n\n\
+            - The first Elf: `6000`\n\
+            - The second Elf: *`24000`*\n";
+
+        assert_eq!(
+            render(markdown, 30),
+            format!(
+                "{ANSI_BOLD}--- Day 1: Calorie Counting ---{ANSI_RESET}\n\n\
+                The Elves take turns writing\n\
+                down the number of {ANSI_ITALIC}Calories{ANSI_RESET},\n\
+                see about.\n\n    \
+                1000\n      2000\n\n\
+                - The first Elf: 6000\n\
+                - The second Elf: {ANSI_ITALIC}24000{ANSI_RESET}\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_wrap_indent() {
+        assert_eq!(
+            wrap("a bb ccc dddd", 8, "- ", "  "),
+            "- a bb\n  ccc\n  dddd\n"
+        );
+    }
+}
@@ -0,0 +1,161 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use lazy_static::lazy_static;
+use regex::Regex;
+
+const PART_TWO_HEADING: &str = "--- Part Two ---";
+
+/// Returns the contents of all fenced code blocks in a puzzle description.
+pub fn code_blocks(markdown: &str) -> Vec<String> {
+    let mut blocks = Vec::new();
+    let mut block: Option<String> = None;
+
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            match block.take() {
+                Some(contents) => blocks.push(contents),
+                None => block = Some(String::new()),
+            }
+        } else if let Some(contents) = block.as_mut() {
+            contents.push_str(line);
+            contents.push('\n');
+        }
+    }
+
+    blocks
+}
+
+/// Returns the title of a puzzle, e.g. `Calorie Counting` for `## --- Day 1: Calorie Counting ---`.
+pub fn title(markdown: &str) -> Option<String> {
+    let heading = markdown
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("## --- Day "))?;
+    let (_, title) = heading.trim_end_matches('-').split_once(':')?;
+    Some(title.trim().to_string())
+}
+
+/// Splits a puzzle description into the description of part one and, if unlocked, part two.
+pub fn parts(markdown: &str) -> (&str, Option<&str>) {
+    match markdown.find(PART_TWO_HEADING) {
+        Some(idx) => {
+            let start = markdown[..idx].rfind('\n').map_or(0, |idx| idx + 1);
+            (&markdown[..start], Some(&markdown[start..]))
+        }
+        None => (markdown, None),
+    }
+}
+
+/// Guesses the answer for the example of a part. Puzzles emphasize the example answer,
+/// usually as the last emphasized code span of the part (e.g. *`24000`*).
+pub fn example_answer(part: &str) -> Option<String> {
+    lazy_static! {
+        static ref ANSWER_PTRN: Regex = Regex::new(r"\*`([^`\n]+)`\*").unwrap();
+    }
+
+    ANSWER_PTRN
+        .captures_iter(part)
+        .last()
+        .map(|caps| caps[1].trim().to_string())
+}
+
+/// Returns the example answers for part one and part two.
+pub fn example_answers(markdown: &str) -> (Option<String>, Option<String>) {
+    let (part_one, part_two) = parts(markdown);
+    (example_answer(part_one), part_two.and_then(example_answer))
+}
+
+/// Formats an answer as the `Some(..)` expression of a scaffolded test.
+/// Numbers are only written as literals if the part does not return a `String`.
+fn answer_expr(answer: &str, returns_string: bool) -> String {
+    if !returns_string && answer.parse::<i64>().is_ok() {
+        format!("Some({answer})")
+    } else {
+        format!("Some({answer:?}.to_string())")
+    }
+}
+
+/// Replaces the `None` placeholder in the scaffolded test of `part` with `answer`.
+/// Returns `None` if the module has no placeholder left for that part.
+pub fn fill_test_answer(module: &str, part: u8, answer: &str) -> Option<String> {
+    let func = match part {
+        1 => "part_one",
+        2 => "part_two",
+        _ => return None,
+    };
+
+    let placeholder = format!("assert_eq!({func}(&input), None);");
+    if !module.contains(&placeholder) {
+        return None;
+    }
+
+    let returns_string = module.contains(&format!("fn {func}(input: &str) -> Option<String>"));
+
+    Some(module.replacen(
+        &placeholder,
+        &format!(
+            "assert_eq!({func}(&input), {});",
+            answer_expr(answer, returns_string)
+        ),
+        1,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUZZLE: &str = "## --- Day 5: Supply Stacks ---\n\n\
+        For example:\n\n\
+        ```\n    [D]    \n[N] [C]    \n```\n\n\
+        Crate `1` is moved, the top crates are *`CMZ`*.\n\n\
+        ## --- Part Two ---\n\n\
+        ```\nmove 1 from 2 to 1\n```\n\n\
+        In this example, the CrateMover *`9001`* leaves *`MCD`*.\n";
+
+    #[test]
+    fn test_code_blocks() {
+        assert_eq!(
+            code_blocks(PUZZLE),
+            vec!["    [D]    \n[N] [C]    \n", "move 1 from 2 to 1\n"]
+        );
+    }
+
+    #[test]
+    fn test_title() {
+        assert_eq!(title(PUZZLE).as_deref(), Some("Supply Stacks"));
+        assert_eq!(title("no heading"), None);
+    }
+
+    #[test]
+    fn test_example_answers() {
+        assert_eq!(
+            example_answers(PUZZLE),
+            (Some("CMZ".to_string()), Some("MCD".to_string()))
+        );
+
+        let (part_one, _) = parts(PUZZLE);
+        assert_eq!(example_answers(part_one), (Some("CMZ".to_string()), None));
+    }
+
+    #[test]
+    fn test_fill_test_answer() {
+        let module = "assert_eq!(part_one(&input), None);\nassert_eq!(part_two(&input), None);";
+        assert_eq!(
+            fill_test_answer(module, 1, "24000").as_deref(),
+            Some("assert_eq!(part_one(&input), Some(24000));\nassert_eq!(part_two(&input), None);")
+        );
+        assert_eq!(
+            fill_test_answer(module, 2, "MCD").as_deref(),
+            Some("assert_eq!(part_one(&input), None);\nassert_eq!(part_two(&input), Some(\"MCD\".to_string()));")
+        );
+        assert_eq!(fill_test_answer("", 1, "1"), None);
+
+        let module = "pub fn part_one(input: &str) -> Option<String> {}\nassert_eq!(part_one(&input), None);";
+        assert_eq!(
+            fill_test_answer(module, 1, "24000").as_deref(),
+            Some("pub fn part_one(input: &str) -> Option<String> {}\nassert_eq!(part_one(&input), Some(\"24000\".to_string()));")
+        );
+    }
+}
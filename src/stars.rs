@@ -0,0 +1,189 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{collections::BTreeMap, fmt::Display, fs};
+
+/// File that records accepted answers. Stars are derived from it.
+pub const ANSWERS_PATH: &str = "src/answers.json";
+pub const README_PATH: &str = "README.md";
+/// Marks the position of the progress table in the readme.
+pub const README_MARKER: &str = "<!--- advent_readme_stars table --->";
+
+pub enum StarsError {
+    BadFormat,
+    MarkerNotFound,
+    IoError,
+}
+
+impl Display for StarsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StarsError::BadFormat => write!(f, "\"{ANSWERS_PATH}\" could not be parsed."),
+            StarsError::MarkerNotFound => {
+                write!(f, "\"{README_PATH}\" does not contain \"{README_MARKER}\".")
+            }
+            StarsError::IoError => write!(f, "could not read or write file."),
+        }
+    }
+}
+
+/// Accepted answers by year, day and part.
+#[derive(Default, Debug, PartialEq, Eq)]
+pub struct Answers(BTreeMap<u16, BTreeMap<u8, BTreeMap<u8, String>>>);
+
+impl Answers {
+    pub fn parse(json: &str) -> Result<Self, StarsError> {
+        serde_json::from_str(json)
+            .map(Self)
+            .map_err(|_| StarsError::BadFormat)
+    }
+
+    pub fn load() -> Result<Self, StarsError> {
+        match fs::read_to_string(ANSWERS_PATH) {
+            Ok(json) => Self::parse(&json),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    pub fn save(&self) -> Result<(), StarsError> {
+        let json = serde_json::to_string_pretty(&self.0).map_err(|_| StarsError::BadFormat)?;
+        fs::write(ANSWERS_PATH, json + "\n").map_err(|_| StarsError::IoError)
+    }
+
+    pub fn record(&mut self, year: u16, day: u8, part: u8, answer: &str) {
+        self.0
+            .entry(year)
+            .or_default()
+            .entry(day)
+            .or_default()
+            .insert(part, answer.to_string());
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: u8) -> Option<&str> {
+        self.0.get(&year)?.get(&day)?.get(&part).map(String::as_str)
+    }
+
+    pub fn stars(&self, year: u16) -> usize {
+        self.0
+            .get(&year)
+            .map_or(0, |days| days.values().map(|parts| parts.len()).sum())
+    }
+
+    /// Renders a progress table per recorded year, most recent year first.
+    pub fn render_tables(&self) -> String {
+        let mut out = String::new();
+
+        for (year, days) in self.0.iter().rev() {
+            let last_day = days.keys().max().copied().unwrap_or_default();
+
+            out.push_str(&format!("## {year} Results\n\n"));
+            out.push_str("| Day | Part 1 | Part 2 |\n");
+            out.push_str("| :---: | :---: | :---: |\n");
+
+            for day in 1..=last_day {
+                let star = |part| {
+                    if self.get(*year, day, part).is_some() {
+                        "⭐"
+                    } else {
+                        " "
+                    }
+                };
+                out.push_str(&format!(
+                    "| [Day {day}](https://adventofcode.com/{year}/day/{day}) | {} | {} |\n",
+                    star(1),
+                    star(2)
+                ));
+            }
+
+            out.push('\n');
+        }
+
+        out
+    }
+}
+
+/// Replaces the progress tables that follow the marker in `readme` with `tables`.
+pub fn update_readme(readme: &str, tables: &str) -> Result<String, StarsError> {
+    let marker_end = readme
+        .find(README_MARKER)
+        .map(|idx| idx + README_MARKER.len())
+        .ok_or(StarsError::MarkerNotFound)?;
+
+    let (head, rest) = readme.split_at(marker_end);
+
+    // skip previously generated tables: result headings, table rows and blank lines between them.
+    let mut generated_len = 0;
+    for line in rest.split_inclusive('\n') {
+        let trimmed = line.trim();
+        let is_generated = trimmed.is_empty()
+            || trimmed.starts_with('|')
+            || (trimmed.starts_with("## ") && trimmed.ends_with(" Results"));
+
+        if !is_generated {
+            break;
+        }
+        generated_len += line.len();
+    }
+
+    let tail = &rest[generated_len..];
+    Ok(format!("{head}\n\n{tables}{tail}"))
+}
+
+/// Regenerates the progress tables in the readme from the recorded answers.
+pub fn write_readme(answers: &Answers) -> Result<(), StarsError> {
+    let readme = fs::read_to_string(README_PATH).map_err(|_| StarsError::IoError)?;
+    let updated = update_readme(&readme, &answers.render_tables())?;
+    fs::write(README_PATH, updated).map_err(|_| StarsError::IoError)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answers() {
+        let mut answers = Answers::parse(r#"{"2022": {"1": {"1": "24000"}}}"#)
+            .ok()
+            .unwrap();
+        answers.record(2022, 3, 2, "70");
+
+        assert_eq!(answers.get(2022, 1, 1), Some("24000"));
+        assert_eq!(answers.get(2022, 1, 2), None);
+        assert_eq!(answers.stars(2022), 2);
+        assert_eq!(answers.stars(2021), 0);
+    }
+
+    #[test]
+    fn test_update_readme() {
+        let mut answers = Answers::default();
+        answers.record(2022, 1, 1, "24000");
+        answers.record(2022, 1, 2, "45000");
+        answers.record(2022, 2, 1, "15");
+
+        let tables = answers.render_tables();
+        assert_eq!(
+            tables,
+            "## 2022 Results\n\n\
+            | Day | Part 1 | Part 2 |\n\
+            | :---: | :---: | :---: |\n\
+            | [Day 1](https://adventofcode.com/2022/day/1) | ⭐ | ⭐ |\n\
+            | [Day 2](https://adventofcode.com/2022/day/2) | ⭐ |   |\n\n"
+        );
+
+        let readme = format!("# AoC\n\n{README_MARKER}\n\n---\n\n## Usage\n");
+        let updated = update_readme(&readme, &tables).ok().unwrap();
+        assert_eq!(
+            updated,
+            format!("# AoC\n\n{README_MARKER}\n\n{tables}---\n\n## Usage\n")
+        );
+
+        // regenerating replaces the previous tables.
+        assert_eq!(update_readme(&updated, &tables).ok(), Some(updated));
+
+        assert!(matches!(
+            update_readme("# AoC\n", &tables),
+            Err(StarsError::MarkerNotFound)
+        ));
+    }
+}
@@ -0,0 +1,297 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    env,
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+/// Folder in the repository that holds module templates, e.g. `templates/grid.rs`.
+pub const TEMPLATES_FOLDER: &str = "templates";
+pub const DEFAULT_TEMPLATE: &str = "plain";
+
+/// Fallbacks for when the repository's copy of a built-in template was removed.
+const BUILTIN_TEMPLATES: [(&str, &str); 3] = [
+    ("plain", include_str!("../templates/plain.rs")),
+    ("grid", include_str!("../templates/grid.rs")),
+    ("parse", include_str!("../templates/parse.rs")),
+];
+
+pub enum TemplateError {
+    NotFound(String),
+    IoError,
+}
+
+impl Display for TemplateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TemplateError::NotFound(name) => write!(
+                f,
+                "template \"{name}\" not found. Add it as \"{TEMPLATES_FOLDER}/{name}.rs\" or use one of: {}.",
+                names().join(", ")
+            ),
+            TemplateError::IoError => write!(f, "could not read template file."),
+        }
+    }
+}
+
+pub const DEFAULT_ANSWER_TYPE: &str = "u32";
+
+/// Returns the rust type for an answer type that can be passed to `scaffold --type`.
+pub fn answer_type(name: &str) -> Option<&'static str> {
+    match name {
+        "u32" => Some("u32"),
+        "u64" => Some("u64"),
+        "i64" => Some("i64"),
+        "usize" => Some("usize"),
+        "string" | "String" => Some("String"),
+        _ => None,
+    }
+}
+
+/// Values that are filled into a template.
+pub struct Placeholders {
+    pub day: u8,
+    pub year: u16,
+    /// Rust type of the answers, e.g. `u64`.
+    pub answer_type: String,
+    /// Puzzle title, empty if the puzzle was not downloaded yet.
+    pub title: String,
+}
+
+impl Placeholders {
+    /// Replaces `{{day}}`, `{{day_padded}}`, `{{year}}`, `{{title}}` and `{{type}}` in `template`.
+    pub fn render(&self, template: &str) -> String {
+        template
+            .replace("{{day}}", &self.day.to_string())
+            .replace("{{day_padded}}", &format!("{:02}", self.day))
+            .replace("{{year}}", &self.year.to_string())
+            .replace("{{title}}", &self.title)
+            .replace("{{type}}", &self.answer_type)
+    }
+
+    /// Renders a module template. If the title is known and the template does not place it
+    /// itself, it is added as a module doc comment.
+    pub fn render_module(&self, template: &str) -> String {
+        let module = self.render(template);
+
+        if self.title.is_empty() || template.contains("{{title}}") {
+            module
+        } else {
+            format!("//! Day {}: {}\n\n{module}", self.day, self.title)
+        }
+    }
+}
+
+/// Folder for personal templates that are not part of the repository,
+/// `$XDG_CONFIG_HOME/advent-of-code/templates` or `~/.config/advent-of-code/templates`.
+pub fn config_folder() -> Option<PathBuf> {
+    let config_home = env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| {
+            env::var_os("HOME")
+                .or_else(|| env::var_os("USERPROFILE"))
+                .map(|home| PathBuf::from(home).join(".config"))
+        })?;
+
+    Some(config_home.join("advent-of-code").join(TEMPLATES_FOLDER))
+}
+
+/// Folders that are searched for templates, in order of precedence.
+fn folders() -> Vec<PathBuf> {
+    let mut folders = vec![PathBuf::from(TEMPLATES_FOLDER)];
+    folders.extend(config_folder());
+    folders
+}
+
+fn template_path(folder: &Path, name: &str) -> PathBuf {
+    folder.join(format!("{name}.rs"))
+}
+
+/// Loads a template by name. The repository's `templates` folder takes precedence over
+/// the user's config folder, which takes precedence over the built-in templates.
+pub fn load(name: &str) -> Result<String, TemplateError> {
+    let is_valid_name = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+
+    if !is_valid_name {
+        return Err(TemplateError::NotFound(name.to_string()));
+    }
+
+    for folder in folders() {
+        let path = template_path(&folder, name);
+        if path.is_file() {
+            return fs::read_to_string(path).map_err(|_| TemplateError::IoError);
+        }
+    }
+
+    BUILTIN_TEMPLATES
+        .iter()
+        .find(|(builtin, _)| *builtin == name)
+        .map(|(_, template)| template.to_string())
+        .ok_or_else(|| TemplateError::NotFound(name.to_string()))
+}
+
+/// Names of all available templates.
+pub fn names() -> Vec<String> {
+    let mut names: Vec<String> = BUILTIN_TEMPLATES
+        .iter()
+        .map(|(name, _)| name.to_string())
+        .collect();
+
+    for folder in folders() {
+        let Ok(entries) = fs::read_dir(folder) else {
+            continue;
+        };
+
+        names.extend(entries.filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()? != "rs" {
+                return None;
+            }
+            path.file_stem()?.to_str().map(str::to_string)
+        }));
+    }
+
+    names.sort();
+    names.dedup();
+    names
+}
+
+/// Returns the rust type the parts of a module return, e.g. `u64` for `Option<u64>`.
+pub fn module_answer_type(module: &str) -> Option<String> {
+    let start = module.find("fn part_one(input: &str) -> Option<")?
+        + "fn part_one(input: &str) -> Option<".len();
+    let len = module[start..].find('>')?;
+    Some(module[start..start + len].trim().to_string())
+}
+
+/// Returns the byte offset of the test module, which templates put at the end of the file.
+fn tests_start(module: &str) -> Option<usize> {
+    module.find("#[cfg(test)]")
+}
+
+/// Replaces the test module of `module` with the one of the rendered template `rendered`.
+/// Returns `None` if either of them has no test module.
+pub fn replace_tests(module: &str, rendered: &str) -> Option<String> {
+    let module_tests = tests_start(module)?;
+    let template_tests = tests_start(rendered)?;
+    Some(format!(
+        "{}{}",
+        &module[..module_tests],
+        &rendered[template_tests..]
+    ))
+}
+
+/// Changes the day a module reads its input and examples for from `from` to `to`.
+pub fn renumber(module: &str, from: u8, to: u8) -> String {
+    lazy_static! {
+        static ref DAY_PTRN: Regex = Regex::new(
+            r#"(read_file(?:_with)?\("(?:inputs|examples)",\s*|test_examples!\(|check_examples\(|//! Day )(\d+)\b"#
+        )
+        .unwrap();
+    }
+
+    DAY_PTRN
+        .replace_all(module, |caps: &regex::Captures| {
+            if caps[2].parse() == Ok(from) {
+                format!("{}{to}", &caps[1])
+            } else {
+                caps[0].to_string()
+            }
+        })
+        .into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let placeholders = Placeholders {
+            day: 5,
+            year: 2022,
+            answer_type: "String".to_string(),
+            title: "Supply Stacks".to_string(),
+        };
+
+        assert_eq!(
+            placeholders.render("//! {{year}} day {{day}} ({{day_padded}}): {{title}}"),
+            "//! 2022 day 5 (05): Supply Stacks"
+        );
+        assert_eq!(
+            placeholders.render("fn part_one() -> Option<{{type}}>"),
+            "fn part_one() -> Option<String>"
+        );
+
+        assert_eq!(
+            placeholders.render_module("fn main() {}\n"),
+            "//! Day 5: Supply Stacks\n\nfn main() {}\n"
+        );
+        assert_eq!(
+            placeholders.render_module("// {{title}}\n"),
+            "// Supply Stacks\n"
+        );
+    }
+
+    const MODULE: &str = "//! Day 5: Supply Stacks\n\n\
+        pub fn part_one(input: &str) -> Option<String> {\n    Some(input.into())\n}\n\n\
+        fn main() {\n    let input = &advent_of_code::read_file(\"inputs\", 5);\n}\n\n\
+        #[cfg(test)]\nmod tests {\n    advent_of_code::test_examples!(5, part_one, part_two);\n\n    \
+        fn test_15() {\n        let input = advent_of_code::read_file(\"examples\", 5);\n    }\n}\n";
+
+    #[test]
+    fn test_module_answer_type() {
+        assert_eq!(module_answer_type(MODULE).as_deref(), Some("String"));
+        assert_eq!(module_answer_type("fn main() {}"), None);
+    }
+
+    #[test]
+    fn test_replace_tests() {
+        let updated =
+            replace_tests(MODULE, "fn main() {}\n\n#[cfg(test)]\nmod tests {}\n").unwrap();
+        assert!(updated.contains("Some(input.into())"));
+        assert!(updated.ends_with("\n\n#[cfg(test)]\nmod tests {}\n"));
+
+        assert_eq!(replace_tests("fn main() {}", MODULE), None);
+    }
+
+    #[test]
+    fn test_renumber() {
+        let renumbered = renumber(MODULE, 5, 15);
+        assert!(renumbered.starts_with("//! Day 15: Supply Stacks"));
+        assert!(renumbered.contains("read_file(\"inputs\", 15)"));
+        assert!(renumbered.contains("read_file(\"examples\", 15)"));
+        assert!(renumbered.contains("test_examples!(15, part_one"));
+        assert!(renumbered.contains("fn test_15()"));
+        assert_eq!(renumber(&renumbered, 5, 7), renumbered);
+    }
+
+    #[test]
+    fn test_answer_type() {
+        assert_eq!(answer_type("string"), Some("String"));
+        assert_eq!(answer_type("u64"), Some("u64"));
+        assert_eq!(answer_type("f64"), None);
+    }
+
+    #[test]
+    fn test_load() {
+        assert!(load("grid")
+            .ok()
+            .unwrap()
+            .contains("fn parse(input: &str) -> Grid"));
+        assert!(matches!(load("../Cargo"), Err(TemplateError::NotFound(_))));
+        assert!(matches!(load("missing"), Err(TemplateError::NotFound(_))));
+        assert!(names().iter().any(|name| name == "plain"));
+    }
+}
@@ -0,0 +1,459 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{fmt::Display, fs, path::Path};
+
+use serde::Serialize;
+use serde_json::{json, ser::PrettyFormatter, Value};
+
+pub const LAUNCH_PATH: &str = ".vscode/launch.json";
+const PACKAGE: &str = "advent_of_code";
+
+pub enum LaunchError {
+    BadFormat,
+    IoError,
+}
+
+impl Display for LaunchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LaunchError::BadFormat => write!(f, "\"{LAUNCH_PATH}\" could not be parsed."),
+            LaunchError::IoError => write!(f, "could not read or write \"{LAUNCH_PATH}\"."),
+        }
+    }
+}
+
+fn executable_name(day: u8) -> String {
+    format!("Debug executable '{day:02}'")
+}
+
+fn tests_name(day: u8) -> String {
+    format!("Debug unit tests in executable '{day:02}'")
+}
+
+/// Returns the day of a generated configuration, `None` for other configurations.
+fn generated_day(configuration: &Value) -> Option<u8> {
+    let name = configuration.get("name")?.as_str()?;
+    let day = name
+        .strip_prefix("Debug executable '")
+        .or_else(|| name.strip_prefix("Debug unit tests in executable '"))?
+        .strip_suffix('\'')?;
+
+    if day.len() != 2 {
+        return None;
+    }
+    day.parse().ok()
+}
+
+fn configuration(day: u8, tests: bool) -> Value {
+    let bin = format!("{day:02}");
+    let (name, args) = if tests {
+        (tests_name(day), vec!["test", "--no-run"])
+    } else {
+        (executable_name(day), vec!["build"])
+    };
+    let mut cargo_args: Vec<String> = args.into_iter().map(str::to_string).collect();
+    cargo_args.push(format!("--bin={bin}"));
+    cargo_args.push(format!("--package={PACKAGE}"));
+
+    json!({
+        "type": "lldb",
+        "request": "launch",
+        "name": name,
+        "cargo": {
+            "args": cargo_args,
+            "filter": {
+                "name": bin,
+                "kind": "bin"
+            }
+        },
+        "args": [],
+        "cwd": "${workspaceFolder}"
+    })
+}
+
+/// Removes `//` and `/* */` comments, which VS Code allows in its config files.
+fn strip_comments(jsonc: &str) -> String {
+    let mut out = String::with_capacity(jsonc.len());
+    let mut chars = jsonc.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(ch) = chars.next() {
+        if in_string {
+            out.push(ch);
+            match ch {
+                '\\' => out.extend(chars.next()),
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match (ch, chars.peek()) {
+            ('"', _) => {
+                in_string = true;
+                out.push(ch);
+            }
+            ('/', Some('/')) => while chars.next_if(|&next| next != '\n').is_some() {},
+            ('/', Some('*')) => {
+                chars.next();
+                let mut prev = ' ';
+                for next in chars.by_ref() {
+                    if prev == '*' && next == '/' {
+                        break;
+                    }
+                    prev = next;
+                }
+            }
+            _ => out.push(ch),
+        }
+    }
+
+    out
+}
+
+/// Returns the position after the string that starts at `pos`.
+fn string_end(bytes: &[u8], mut pos: usize) -> Option<usize> {
+    pos += 1;
+    loop {
+        match bytes.get(pos)? {
+            b'\\' => pos += 2,
+            b'"' => return Some(pos + 1),
+            _ => pos += 1,
+        }
+    }
+}
+
+/// Returns the position of the next character that is neither whitespace nor part of a comment.
+fn skip_trivia(bytes: &[u8], mut pos: usize) -> usize {
+    loop {
+        match (bytes.get(pos), bytes.get(pos + 1)) {
+            (Some(b), _) if b.is_ascii_whitespace() => pos += 1,
+            (Some(b'/'), Some(b'/')) => {
+                while bytes.get(pos).is_some_and(|&b| b != b'\n') {
+                    pos += 1;
+                }
+            }
+            (Some(b'/'), Some(b'*')) => {
+                pos = bytes[pos + 2..]
+                    .windows(2)
+                    .position(|window| window == b"*/")
+                    .map_or(bytes.len(), |idx| pos + 2 + idx + 2);
+            }
+            _ => return pos,
+        }
+    }
+}
+
+/// Returns the position after the value that starts at `pos`.
+fn value_end(bytes: &[u8], mut pos: usize) -> Option<usize> {
+    match bytes.get(pos)? {
+        b'"' => string_end(bytes, pos),
+        b'{' | b'[' => {
+            let mut depth = 0;
+            loop {
+                match bytes.get(pos)? {
+                    b'"' => pos = string_end(bytes, pos)?,
+                    b'/' => pos = skip_trivia(bytes, pos).max(pos + 1),
+                    b'{' | b'[' => {
+                        depth += 1;
+                        pos += 1;
+                    }
+                    b'}' | b']' => {
+                        depth -= 1;
+                        pos += 1;
+                        if depth == 0 {
+                            return Some(pos);
+                        }
+                    }
+                    _ => pos += 1,
+                }
+            }
+        }
+        _ => {
+            while bytes
+                .get(pos)
+                .is_some_and(|b| !b.is_ascii_whitespace() && !b",]}/".contains(b))
+            {
+                pos += 1;
+            }
+            Some(pos)
+        }
+    }
+}
+
+/// Returns the range of the value of `key` in the top-level object.
+fn member_value(bytes: &[u8], key: &str) -> Option<(usize, usize)> {
+    let mut pos = skip_trivia(bytes, 0);
+    if bytes.get(pos) != Some(&b'{') {
+        return None;
+    }
+
+    loop {
+        let key_start = skip_trivia(bytes, pos + 1);
+        if bytes.get(key_start) != Some(&b'"') {
+            return None;
+        }
+        let key_end = string_end(bytes, key_start)?;
+
+        pos = skip_trivia(bytes, key_end);
+        if bytes.get(pos) != Some(&b':') {
+            return None;
+        }
+
+        let start = skip_trivia(bytes, pos + 1);
+        let end = value_end(bytes, start)?;
+        if &bytes[key_start + 1..key_end - 1] == key.as_bytes() {
+            return Some((start, end));
+        }
+
+        pos = skip_trivia(bytes, end);
+        if bytes.get(pos) != Some(&b',') {
+            return None;
+        }
+    }
+}
+
+/// Returns the ranges of the elements of the array at `start..end`.
+fn elements(bytes: &[u8], (start, end): (usize, usize)) -> Option<Vec<(usize, usize)>> {
+    let mut elements = Vec::new();
+    let mut pos = skip_trivia(bytes, start + 1);
+
+    while pos < end - 1 {
+        let element_end = value_end(bytes, pos)?;
+        if element_end == pos {
+            return None;
+        }
+        elements.push((pos, element_end));
+
+        pos = skip_trivia(bytes, element_end);
+        if bytes.get(pos) == Some(&b',') {
+            pos = skip_trivia(bytes, pos + 1);
+        }
+    }
+
+    Some(elements)
+}
+
+/// Returns the whitespace at the start of the line that contains `pos`.
+fn indentation(text: &str, pos: usize) -> &str {
+    let line_start = text[..pos].rfind('\n').map_or(0, |idx| idx + 1);
+    let line = &text[line_start..];
+    &line[..line.len() - line.trim_start().len()]
+}
+
+fn render(configuration: &Value, indent: &str) -> Result<String, LaunchError> {
+    let mut out = Vec::new();
+    let mut serializer =
+        serde_json::Serializer::with_formatter(&mut out, PrettyFormatter::with_indent(b"    "));
+    configuration
+        .serialize(&mut serializer)
+        .map_err(|_| LaunchError::BadFormat)?;
+    let json = String::from_utf8(out).map_err(|_| LaunchError::BadFormat)?;
+
+    Ok(json.replace('\n', &format!("\n{indent}")))
+}
+
+/// Adds or refreshes the debug configurations for the binaries and tests of `days`.
+/// Generated configurations of days that no longer exist are removed. Only generated configurations
+/// are touched, everything else in the file is kept as it is.
+pub fn update_launch(jsonc: &str, days: &[u8]) -> Result<String, LaunchError> {
+    let launch: Value =
+        serde_json::from_str(&strip_comments(jsonc)).map_err(|_| LaunchError::BadFormat)?;
+    if !launch.get("configurations").is_some_and(Value::is_array) {
+        return Err(LaunchError::BadFormat);
+    }
+
+    let bytes = jsonc.as_bytes();
+    let (open, close) = member_value(bytes, "configurations").ok_or(LaunchError::BadFormat)?;
+    let elements = elements(bytes, (open, close)).ok_or(LaunchError::BadFormat)?;
+
+    let outer_indent = indentation(jsonc, open);
+    let indent = match elements.first() {
+        Some(&(start, _)) => indentation(jsonc, start).to_string(),
+        None => format!("{outer_indent}    "),
+    };
+
+    let mut array = String::from("[");
+    let mut is_first = true;
+    let mut existing = Vec::new();
+
+    for (idx, &(start, end)) in elements.iter().enumerate() {
+        let text = &jsonc[start..end];
+        let element: Value =
+            serde_json::from_str(&strip_comments(text)).map_err(|_| LaunchError::BadFormat)?;
+
+        let text = match generated_day(&element) {
+            Some(day) if !days.contains(&day) => continue,
+            Some(day) => {
+                let generated =
+                    configuration(day, element.get("name") == Some(&tests_name(day).into()));
+                existing.push(generated.get("name").cloned());
+
+                if element == generated {
+                    text.to_string()
+                } else {
+                    render(&generated, &indent)?
+                }
+            }
+            None => text.to_string(),
+        };
+
+        // the first kept element takes the place of the original first element.
+        let separator = if is_first {
+            &jsonc[open + 1..elements[0].0]
+        } else {
+            &jsonc[elements[idx - 1].1..start]
+        };
+        array.push_str(separator);
+        array.push_str(&text);
+        is_first = false;
+    }
+
+    for &day in days {
+        for tests in [true, false] {
+            let generated = configuration(day, tests);
+            if existing.contains(&generated.get("name").cloned()) {
+                continue;
+            }
+
+            if is_first && !elements.is_empty() {
+                array.push_str(&jsonc[open + 1..elements[0].0]);
+            } else if is_first {
+                array.push_str(jsonc[open + 1..close - 1].trim_end());
+                array.push_str(&format!("\n{indent}"));
+            } else {
+                array.push_str(&format!(",\n{indent}"));
+            }
+            array.push_str(&render(&generated, &indent)?);
+            is_first = false;
+        }
+    }
+
+    match elements.last() {
+        Some(&(_, end)) => array.push_str(&jsonc[end..close - 1]),
+        None if is_first => array.push_str(&jsonc[open + 1..close - 1]),
+        None => array.push_str(&format!("\n{outer_indent}")),
+    }
+    array.push(']');
+
+    Ok(format!("{}{array}{}", &jsonc[..open], &jsonc[close..]))
+}
+
+/// Returns the days that have a module in `src/bin`.
+pub fn days() -> Vec<u8> {
+    let Ok(entries) = fs::read_dir("src/bin") else {
+        return Vec::new();
+    };
+
+    let mut days: Vec<u8> = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let stem = path.file_stem()?.to_str()?;
+            if path.extension()? != "rs" || stem.len() != 2 {
+                return None;
+            }
+            stem.parse().ok()
+        })
+        .collect();
+
+    days.sort_unstable();
+    days
+}
+
+/// Updates the debug configurations in the launch file for all days.
+/// Does nothing if the project does not have a launch file.
+pub fn sync_launch() -> Result<bool, LaunchError> {
+    if !Path::new(LAUNCH_PATH).is_file() {
+        return Ok(false);
+    }
+
+    let jsonc = fs::read_to_string(LAUNCH_PATH).map_err(|_| LaunchError::IoError)?;
+    let updated = update_launch(&jsonc, &days())?;
+
+    if updated != jsonc {
+        fs::write(LAUNCH_PATH, updated).map_err(|_| LaunchError::IoError)?;
+    }
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LAUNCH: &str = r#"{
+    // Use IntelliSense to learn about possible attributes.
+    "version": "0.2.0",
+    "configurations": [
+        {
+            "name": "Custom", /* keeps key order */ "type": "lldb",
+            "args": ["// not a comment"]
+        },
+        {
+            "type": "lldb",
+            "name": "Debug executable '03'"
+        }
+    ]
+}
+"#;
+
+    #[test]
+    fn test_strip_comments() {
+        assert_eq!(
+            strip_comments("{\"a\": \"//b\\\"\", // c\n/* d */ \"e\": 1}"),
+            "{\"a\": \"//b\\\"\", \n \"e\": 1}"
+        );
+    }
+
+    #[test]
+    fn test_update_launch() {
+        let updated = update_launch(LAUNCH, &[1]).ok().unwrap();
+
+        // everything up to the custom configuration is kept as it is.
+        let custom_end = LAUNCH.find("},").unwrap() + 1;
+        assert!(updated.starts_with(&LAUNCH[..custom_end]));
+        assert!(updated[custom_end..].starts_with(",\n        {\n            \"type\": \"lldb\","));
+        assert!(updated.ends_with("        }\n    ]\n}\n"));
+        assert!(!updated.contains("'03'"));
+        assert!(updated.contains("\"Debug executable '01'\""));
+        assert!(updated.contains("\"Debug unit tests in executable '01'\""));
+        assert!(updated.contains("\"--bin=01\""));
+
+        // refreshing is idempotent.
+        assert_eq!(update_launch(&updated, &[1]).ok(), Some(updated));
+    }
+
+    #[test]
+    fn test_update_launch_in_place() {
+        let executable = serde_json::to_string(&configuration(2, false)).unwrap();
+        let launch = format!(
+            "{{\"configurations\": [\n  {executable}, // up to date\n  {{\"name\": \"Debug unit tests in executable '02'\"}}\n]}}"
+        );
+
+        let updated = update_launch(&launch, &[2]).ok().unwrap();
+        assert!(updated.starts_with(&format!("{{\"configurations\": [\n  {executable}, // up to date\n  {{\n      \"type\": \"lldb\",")));
+        assert!(updated.ends_with("\n  }\n]}"));
+        assert!(serde_json::from_str::<Value>(&strip_comments(&updated)).is_ok());
+
+        let removed = update_launch(&launch, &[]).ok();
+        assert_eq!(removed.as_deref(), Some("{\"configurations\": [\n]}"));
+
+        let added = update_launch("{\"configurations\": []}", &[2])
+            .ok()
+            .unwrap();
+        assert!(added.starts_with("{\"configurations\": [\n    {\n        \"type\": \"lldb\","));
+        assert!(added.ends_with("\n    }\n]}"));
+        assert_eq!(update_launch(&added, &[2]).ok(), Some(added));
+    }
+
+    #[test]
+    fn test_generated_day() {
+        assert_eq!(generated_day(&configuration(7, true)), Some(7));
+        assert_eq!(generated_day(&configuration(12, false)), Some(12));
+        assert_eq!(
+            generated_day(&json!({"name": "Debug executable 'advent_of_code'"})),
+            None
+        );
+    }
+}
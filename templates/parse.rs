@@ -0,0 +1,51 @@
+use advent_of_code::helpers::parse_lines;
+
+#[allow(dead_code)]
+#[derive(Debug)]
+struct Entry<'a> {
+    line: &'a str,
+}
+
+/// Parses a line, e.g. with `advent_of_code::scan!(line, "move {} from {} to {}", u32, u32, u32)`.
+fn parse_line(line: &str) -> Option<Entry<'_>> {
+    Some(Entry { line })
+}
+
+fn parse(input: &str) -> Vec<Entry<'_>> {
+    parse_lines(input, parse_line).unwrap_or_else(|e| panic!("{e}"))
+}
+
+pub fn part_one(input: &str) -> Option<{{type}}> {
+    let _entries = parse(input);
+    None
+}
+
+pub fn part_two(input: &str) -> Option<{{type}}> {
+    let _entries = parse(input);
+    None
+}
+
+fn main() {
+    let input = &advent_of_code::read_file("inputs", {{day}});
+    advent_of_code::solve!(1, part_one, input);
+    advent_of_code::solve!(2, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    advent_of_code::test_examples!({{day}}, part_one, part_two);
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", {{day}});
+        assert_eq!(part_one(&input), None);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", {{day}});
+        assert_eq!(part_two(&input), None);
+    }
+}
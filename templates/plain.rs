@@ -0,0 +1,32 @@
+pub fn part_one(input: &str) -> Option<{{type}}> {
+    None
+}
+
+pub fn part_two(input: &str) -> Option<{{type}}> {
+    None
+}
+
+fn main() {
+    let input = &advent_of_code::read_file("inputs", {{day}});
+    advent_of_code::solve!(1, part_one, input);
+    advent_of_code::solve!(2, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    advent_of_code::test_examples!({{day}}, part_one, part_two);
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", {{day}});
+        assert_eq!(part_one(&input), None);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", {{day}});
+        assert_eq!(part_two(&input), None);
+    }
+}